@@ -0,0 +1,70 @@
+use crate::errors::AppError;
+use crate::get_keccak_hash::keccak_hash_bytes;
+use crate::rlp_codec::rlp_encode_block_header;
+use crate::state::State;
+use crate::types::{Block, Result};
+
+fn check_block_hash_matches_header(block: &Block) -> Result<()> {
+    rlp_encode_block_header(block)
+        .and_then(|rlp_encoded_header| keccak_hash_bytes(&rlp_encoded_header))
+        .and_then(|header_hash| match header_hash == block.hash {
+            true => Ok(()),
+            false => Err(AppError::Custom(format!(
+                "✘ Block hash mismatch!\n    ✘ Block hash:  {:?}\n    ✘ Header hash: {:?}",
+                block.hash, header_hash,
+            ))),
+        })
+}
+
+pub fn check_block_hash_in_state(state: State) -> Result<State> {
+    info!("✔ Checking block hash against hash of RLP-encoded header...");
+    check_block_hash_matches_header(state.get_block_from_state()?).map(|_| {
+        info!("✔ Block hash matches header!");
+        state
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{get_expected_block, get_valid_initial_state};
+    use ethereum_types::U256;
+
+    #[test]
+    fn should_pass_block_hash_check_for_valid_block() {
+        let block = get_expected_block();
+        let state = get_valid_initial_state()
+            .and_then(|state| state.set_block_in_state(block.clone()))
+            .unwrap();
+        let result = check_block_hash_in_state(state).unwrap();
+        assert!(result.get_block_from_state().unwrap().hash == block.hash);
+    }
+
+    #[test]
+    fn should_fail_block_hash_check_if_header_field_was_tampered_with() {
+        let mut block = get_expected_block();
+        block.gas_used += U256::one();
+        let header_hash = keccak_hash_bytes(&rlp_encode_block_header(&block).unwrap()).unwrap();
+        let expected_err = format!(
+            "✘ Block hash mismatch!\n    ✘ Block hash:  {:?}\n    ✘ Header hash: {:?}",
+            block.hash, header_hash,
+        );
+        let state = get_valid_initial_state()
+            .and_then(|state| state.set_block_in_state(block))
+            .unwrap();
+        match check_block_hash_in_state(state) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Block hash check should have failed!"),
+        }
+    }
+
+    #[test]
+    fn should_err_checking_block_hash_if_no_block_in_state() {
+        let expected_err = "✘ No block in state!";
+        let state = get_valid_initial_state().unwrap();
+        match check_block_hash_in_state(state) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Block hash check should have failed!"),
+        }
+    }
+}
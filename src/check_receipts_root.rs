@@ -0,0 +1,91 @@
+use crate::errors::AppError;
+use crate::state::State;
+use crate::types::{Block, Result};
+use ethereum_types::H256;
+
+fn check_receipts_root_matches_block(
+    block: &Block,
+    receipts_root: &H256,
+    num_receipts: usize,
+) -> Result<()> {
+    match &block.receipts_root == receipts_root {
+        true => Ok(()),
+        false => Err(AppError::Custom(format!(
+            "✘ Receipts root mismatch in block {:?}!\n    ✘ Block receipts root:    {:?}\n    ✘ Computed receipts root: {:?}\n    ✘ Number of receipts:     {}",
+            block.hash, block.receipts_root, receipts_root, num_receipts,
+        ))),
+    }
+}
+
+pub fn check_receipts_root_in_state(state: State) -> Result<State> {
+    info!("✔ Checking receipts trie root against block's receipts root...");
+    check_receipts_root_matches_block(
+        state.get_block_from_state()?,
+        &state.get_receipts_trie_from_state()?.root,
+        state.get_receipts_from_state()?.len(),
+    )
+    .map(|_| {
+        info!("✔ Receipts root matches block!");
+        state
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_receipts_trie::get_receipts_trie_and_set_in_state;
+    use crate::test_utils::{
+        get_expected_block, get_sample_receipts, get_sample_tx_hashes_1, get_valid_initial_state,
+        RECEIPTS_ROOT_1, SAMPLE_RECEIPT_JSONS_1_PATH,
+    };
+    use crate::utils::convert_hex_to_h256;
+
+    fn get_state_with_block_and_receipts_trie(block: Block) -> State {
+        let receipts = get_sample_receipts(
+            SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+            get_sample_tx_hashes_1(),
+        );
+        get_valid_initial_state()
+            .and_then(|state| state.set_block_in_state(block))
+            .and_then(|state| state.set_receipts_in_state(receipts))
+            .and_then(get_receipts_trie_and_set_in_state)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_pass_receipts_root_check_if_roots_match() {
+        let mut block = get_expected_block();
+        block.receipts_root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let state = get_state_with_block_and_receipts_trie(block);
+        let result = check_receipts_root_in_state(state).unwrap();
+        let root = result.get_receipts_trie_from_state().unwrap().root;
+        assert!(root == result.get_block_from_state().unwrap().receipts_root);
+    }
+
+    #[test]
+    fn should_fail_receipts_root_check_if_roots_do_not_match() {
+        let block = get_expected_block();
+        let state = get_state_with_block_and_receipts_trie(block.clone());
+        let num_receipts = state.get_receipts_from_state().unwrap().len();
+        let expected_err = format!(
+            "✘ Receipts root mismatch in block {:?}!\n    ✘ Block receipts root:    {:?}\n    ✘ Computed receipts root: {}\n    ✘ Number of receipts:     {}",
+            block.hash, block.receipts_root, RECEIPTS_ROOT_1, num_receipts,
+        );
+        match check_receipts_root_in_state(state) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Receipts root check should have failed!"),
+        }
+    }
+
+    #[test]
+    fn should_err_checking_receipts_root_if_no_receipts_trie_in_state() {
+        let expected_err = "✘ No receipts_trie in state!";
+        let state = get_valid_initial_state()
+            .and_then(|state| state.set_block_in_state(get_expected_block()))
+            .unwrap();
+        match check_receipts_root_in_state(state) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Receipts root check should have failed!"),
+        }
+    }
+}
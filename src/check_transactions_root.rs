@@ -0,0 +1,86 @@
+use crate::errors::AppError;
+use crate::state::State;
+use crate::types::{Block, Result};
+use ethereum_types::H256;
+
+fn check_transactions_root_matches_block(
+    block: &Block,
+    transactions_root: &H256,
+    num_transactions: usize,
+) -> Result<()> {
+    match &block.transactions_root == transactions_root {
+        true => Ok(()),
+        false => Err(AppError::Custom(format!(
+            "✘ Transactions root mismatch in block {:?}!\n    ✘ Block transactions root:    {:?}\n    ✘ Computed transactions root: {:?}\n    ✘ Number of transactions:     {}",
+            block.hash, block.transactions_root, transactions_root, num_transactions,
+        ))),
+    }
+}
+
+pub fn check_transactions_root_in_state(state: State) -> Result<State> {
+    info!("✔ Checking transactions trie root against block's transactions root...");
+    check_transactions_root_matches_block(
+        state.get_block_from_state()?,
+        &state.get_transactions_trie_from_state()?.root,
+        state.get_transactions_from_state()?.len(),
+    )
+    .map(|_| {
+        info!("✔ Transactions root matches block!");
+        state
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_transactions_trie::get_transactions_trie_and_set_in_state;
+    use crate::test_utils::{
+        get_expected_block, get_sample_transactions, get_valid_initial_state, TRANSACTIONS_ROOT,
+    };
+    use crate::utils::convert_hex_to_h256;
+
+    fn get_state_with_block_and_transactions_trie(block: Block) -> State {
+        get_valid_initial_state()
+            .and_then(|state| state.set_block_in_state(block))
+            .and_then(|state| state.set_transactions_in_state(get_sample_transactions()))
+            .and_then(get_transactions_trie_and_set_in_state)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_pass_transactions_root_check_if_roots_match() {
+        let mut block = get_expected_block();
+        block.transactions_root = convert_hex_to_h256(TRANSACTIONS_ROOT.to_string()).unwrap();
+        let state = get_state_with_block_and_transactions_trie(block);
+        let result = check_transactions_root_in_state(state).unwrap();
+        let root = result.get_transactions_trie_from_state().unwrap().root;
+        assert!(root == result.get_block_from_state().unwrap().transactions_root);
+    }
+
+    #[test]
+    fn should_fail_transactions_root_check_if_roots_do_not_match() {
+        let block = get_expected_block();
+        let state = get_state_with_block_and_transactions_trie(block.clone());
+        let num_transactions = state.get_transactions_from_state().unwrap().len();
+        let expected_err = format!(
+            "✘ Transactions root mismatch in block {:?}!\n    ✘ Block transactions root:    {:?}\n    ✘ Computed transactions root: {}\n    ✘ Number of transactions:     {}",
+            block.hash, block.transactions_root, TRANSACTIONS_ROOT, num_transactions,
+        );
+        match check_transactions_root_in_state(state) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Transactions root check should have failed!"),
+        }
+    }
+
+    #[test]
+    fn should_err_checking_transactions_root_if_no_transactions_trie_in_state() {
+        let expected_err = "✘ No transactions_trie in state!";
+        let state = get_valid_initial_state()
+            .and_then(|state| state.set_block_in_state(get_expected_block()))
+            .unwrap();
+        match check_transactions_root_in_state(state) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Transactions root check should have failed!"),
+        }
+    }
+}
@@ -4,6 +4,12 @@ use ethereum_types::H256;
 
 pub const ZERO_BYTE: u8 = 0u8;
 pub const HASH_LENGTH: usize = 32;
+pub const LEGACY_TX_TYPE: Byte = 0u8;
+pub const ACCESS_LIST_TX_TYPE: Byte = 1u8;
+pub const DYNAMIC_FEE_TX_TYPE: Byte = 2u8;
+pub const BLOB_TX_TYPE: Byte = 3u8;
+pub const SET_CODE_TX_TYPE: Byte = 4u8;
+pub const MAX_TX_TYPE: Byte = 0x7fu8; // NOTE: Per EIP-2718, types are in [0x00, 0x7f]
 pub const HASH_HEX_CHARS: usize = 64;
 pub const HEX_PREFIX_LENGTH: usize = 2;
 pub const NUM_BITS_IN_NIBBLE: usize = 4;
@@ -14,7 +20,9 @@ pub static DOT_ENV_PATH: &str = "./.env";
 pub static LEAF_NODE_STRING: &str = "leaf";
 pub static BRANCH_NODE_STRING: &str = "branch";
 pub static EXTENSION_NODE_STRING: &str = "extension";
+pub static NULL_NODE_STRING: &str = "null";
 pub const HASHED_NULL_NODE: H256 = H256(HASHED_NULL_NODE_BYTES);
+pub const HASHED_EMPTY_CODE: H256 = H256(HASHED_EMPTY_CODE_BYTES);
 pub static DEFAULT_ENDPOINT: &str = "http://localhost:8545/";
 pub const EMPTY_NIBBLES: Nibbles = Nibbles {
     data: Vec::new(),
@@ -26,3 +34,9 @@ const HASHED_NULL_NODE_BYTES: [u8; 32] = [
     0x56, 0xe8, 0x1f, 0x17, 0x1b, 0xcc, 0x55, 0xa6, 0xff, 0x83, 0x45, 0xe6, 0x92, 0xc0, 0xf8, 0x6e,
     0x5b, 0x48, 0xe0, 0x1b, 0x99, 0x6c, 0xad, 0xc0, 0x01, 0x62, 0x2f, 0xb5, 0xe3, 0x63, 0xb4, 0x21,
 ];
+
+const HASHED_EMPTY_CODE_BYTES: [u8; 32] = [
+    // NOTE: keccak hash of no bytes at all, the code hash of an account without code
+    0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7, 0x03, 0xc0,
+    0xe5, 0x00, 0xb6, 0x53, 0xca, 0x82, 0x27, 0x3b, 0x7b, 0xfa, 0xd8, 0x04, 0x5d, 0x85, 0xa4, 0x70,
+];
@@ -1,9 +1,11 @@
+use ethereum_types::H256;
 use std::{error::Error, fmt};
 
 #[derive(Debug)]
 pub enum AppError {
     Custom(String),
     NoneError(String),
+    MissingNodeError(H256),
     IOError(std::io::Error),
     HexError(hex::FromHexError),
     ReqwestError(reqwest::Error),
@@ -19,6 +21,9 @@ impl fmt::Display for AppError {
             AppError::HexError(ref e) => format!("✘ Hex Error!\n✘ {}", e),
             AppError::IOError(ref e) => format!("✘ I/O Error!\n✘ {}", e),
             AppError::NoneError(ref e) => format!("✘ Nothing to unwrap!\n✘ {:?}", e),
+            AppError::MissingNodeError(ref hash) => {
+                format!("✘ Missing node!\n✘ No node in store w/ hash: {:?}", hash)
+            }
             AppError::SerdeJsonError(ref e) => format!("✘ Serde-Json Error!\n✘ {}", e),
             AppError::TermLogError(ref e) => format!("✘ Terminal logger error: {}", e),
             AppError::SetLoggerError(ref e) => format!("✘ Error setting up logger!\n✘ {}", e),
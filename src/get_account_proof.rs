@@ -0,0 +1,117 @@
+use crate::constants::HASH_LENGTH;
+use crate::errors::AppError;
+use crate::get_rpc_call_jsons::get_proof_json;
+use crate::make_rpc_call::{deserialize_to_proof_rpc_response, get_response_text, make_rpc_call};
+use crate::types::{
+    Account, AccountProof, Bytes, ProofJson, Result, StorageProof, StorageProofJson,
+};
+use crate::utils::{
+    convert_hex_to_address, convert_hex_to_bytes, convert_hex_to_h256, convert_hex_to_u256,
+    decode_prefixed_hex,
+};
+use ethereum_types::H256;
+
+fn convert_storage_key_hex_to_h256(hex: String) -> Result<H256> {
+    decode_prefixed_hex(hex.clone()).and_then(|bytes| match bytes.len() <= HASH_LENGTH {
+        true => Ok(H256::from_slice(
+            &[vec![0u8; HASH_LENGTH - bytes.len()], bytes].concat(),
+        )),
+        false => Err(AppError::Custom(format!(
+            "✘ Storage key is longer than 32 bytes: {}",
+            hex
+        ))),
+    })
+}
+
+fn convert_hex_proof_nodes_to_bytes(hex_nodes: Vec<String>) -> Result<Vec<Bytes>> {
+    hex_nodes
+        .into_iter()
+        .map(convert_hex_to_bytes)
+        .collect::<Result<Vec<Bytes>>>()
+}
+
+fn deserialize_storage_proof_json_to_storage_proof_struct(
+    storage_proof: StorageProofJson,
+) -> Result<StorageProof> {
+    Ok(StorageProof {
+        key: convert_storage_key_hex_to_h256(storage_proof.key)?,
+        value: convert_hex_to_u256(storage_proof.value)?,
+        proof: convert_hex_proof_nodes_to_bytes(storage_proof.proof)?,
+    })
+}
+
+pub fn deserialize_proof_json_to_account_proof_struct(proof: ProofJson) -> Result<AccountProof> {
+    Ok(AccountProof {
+        address: convert_hex_to_address(proof.address)?,
+        account: Account {
+            nonce: convert_hex_to_u256(proof.nonce)?,
+            balance: convert_hex_to_u256(proof.balance)?,
+            storage_root: convert_hex_to_h256(proof.storageHash)?,
+            code_hash: convert_hex_to_h256(proof.codeHash)?,
+        },
+        account_proof: convert_hex_proof_nodes_to_bytes(proof.accountProof)?,
+        storage_proof: proof
+            .storageProof
+            .into_iter()
+            .map(deserialize_storage_proof_json_to_storage_proof_struct)
+            .collect::<Result<Vec<StorageProof>>>()?,
+    })
+}
+
+pub fn get_account_proof_from_rpc_response(rpc_response: String) -> Result<AccountProof> {
+    deserialize_to_proof_rpc_response(rpc_response)
+        .and_then(|res| deserialize_proof_json_to_account_proof_struct(res.result))
+}
+
+pub fn get_account_proof(
+    endpoint: &str,
+    address: &str,
+    storage_keys: &[String],
+    block: &str,
+) -> Result<AccountProof> {
+    info!("✔ Getting account proof for address: {}", address);
+    get_proof_json(address, storage_keys, block)
+        .and_then(|rpc_json| make_rpc_call(endpoint, rpc_json))
+        .and_then(get_response_text)
+        .and_then(get_account_proof_from_rpc_response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{get_sample_account_proof, SAMPLE_ACCOUNT_PROOF_JSON_PATH};
+    use ethereum_types::U256;
+    use std::fs;
+
+    #[test]
+    fn should_get_account_proof_from_rpc_response() {
+        let rpc_response = fs::read_to_string(SAMPLE_ACCOUNT_PROOF_JSON_PATH).unwrap();
+        let result = get_account_proof_from_rpc_response(rpc_response).unwrap();
+        let expected_address =
+            convert_hex_to_address("0x7f0d15c7faae65896648c8273b6d7e43f58fa842".to_string())
+                .unwrap();
+        assert!(result.address == expected_address);
+        assert!(result.account.nonce == U256::one());
+        assert!(result.account.balance == U256::from(1_000_000_000_000_000_000u64));
+        assert!(result.account_proof.len() == 2);
+        assert!(result.storage_proof.len() == 4);
+    }
+
+    #[test]
+    fn should_deserialize_storage_proof_keys_to_padded_h256s() {
+        let account_proof = get_sample_account_proof();
+        let storage_proof = &account_proof.storage_proof[1];
+        assert!(storage_proof.key == H256::from_low_u64_be(1));
+        assert!(storage_proof.value == U256::from(0xc0ffee));
+    }
+
+    #[test]
+    fn should_err_if_storage_key_is_too_long() {
+        let too_long_key = format!("0x{}", "ff".repeat(33));
+        let expected_err = format!("✘ Storage key is longer than 32 bytes: {}", too_long_key);
+        match convert_storage_key_hex_to_h256(too_long_key) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Storage key should be too long!"),
+        }
+    }
+}
@@ -13,7 +13,10 @@ use serde_json::Value as Json;
 
 pub fn deserialize_block_json_to_block_struct(block_json: BlockJson) -> Result<Block> {
     Ok(Block {
-        author: convert_hex_to_address(block_json.author)?,
+        author: match block_json.author {
+            Some(author) => convert_hex_to_address(author)?,
+            None => convert_hex_to_address(block_json.miner.clone())?,
+        },
         difficulty: convert_hex_to_u256(block_json.difficulty)?,
         extra_data: convert_hex_to_bytes(block_json.extraData)?,
         gas_limit: convert_hex_to_u256(block_json.gasLimit)?,
@@ -26,18 +29,48 @@ pub fn deserialize_block_json_to_block_struct(block_json: BlockJson) -> Result<B
         number: convert_hex_to_u256(block_json.number)?,
         parent_hash: convert_hex_to_h256(block_json.parentHash)?,
         receipts_root: convert_hex_to_h256(block_json.receiptsRoot)?,
-        seal_fields: (
-            convert_hex_to_bytes(block_json.sealFields.0)?,
-            convert_hex_to_u256(block_json.sealFields.1)?,
-        ),
+        seal_fields: match block_json.sealFields {
+            Some(seal_fields) => Some((
+                convert_hex_to_bytes(seal_fields.0)?,
+                convert_hex_to_u256(seal_fields.1)?,
+            )),
+            None => None,
+        },
         sha3_uncles: convert_hex_to_h256(block_json.sha3Uncles)?,
         size: convert_hex_to_u256(block_json.size)?,
         state_root: convert_hex_to_h256(block_json.stateRoot)?,
         timestamp: convert_hex_to_u256(block_json.timestamp)?,
-        total_difficulty: convert_hex_to_u256(block_json.totalDifficulty)?,
+        total_difficulty: match block_json.totalDifficulty {
+            Some(total_difficulty) => Some(convert_hex_to_u256(total_difficulty)?),
+            None => None,
+        },
         transactions: convert_hex_strings_to_h256s(block_json.transactions)?,
         transactions_root: convert_hex_to_h256(block_json.transactionsRoot)?,
         uncles: convert_hex_strings_to_h256s(block_json.uncles)?,
+        base_fee_per_gas: block_json
+            .baseFeePerGas
+            .map(convert_hex_to_u256)
+            .transpose()?,
+        withdrawals_root: block_json
+            .withdrawalsRoot
+            .map(convert_hex_to_h256)
+            .transpose()?,
+        blob_gas_used: block_json
+            .blobGasUsed
+            .map(convert_hex_to_u256)
+            .transpose()?,
+        excess_blob_gas: block_json
+            .excessBlobGas
+            .map(convert_hex_to_u256)
+            .transpose()?,
+        parent_beacon_block_root: block_json
+            .parentBeaconBlockRoot
+            .map(convert_hex_to_h256)
+            .transpose()?,
+        requests_hash: block_json
+            .requestsHash
+            .map(convert_hex_to_h256)
+            .transpose()?,
     })
 }
 
@@ -79,10 +112,14 @@ pub fn get_block_from_tx_hash_in_state_and_set_in_state(state: State) -> Result<
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::HASHED_NULL_NODE;
     use crate::test_utils::{
-        assert_block_is_correct, get_valid_block_hash_h256, get_valid_state_with_endpoint,
-        SAMPLE_BLOCK_HASH, SAMPLE_BLOCK_JSON_PATH, WORKING_ENDPOINT,
+        assert_block_is_correct, assert_client_block_is_correct, get_expected_block,
+        get_sample_client_block_json_path, get_valid_block_hash_h256,
+        get_valid_state_with_endpoint, SAMPLE_BLOCK_HASH, SAMPLE_BLOCK_JSON_PATH,
+        SAMPLE_CLIENT_NAMES, SAMPLE_PRAGUE_BLOCK_JSON_PATH, WORKING_ENDPOINT,
     };
+    use ethereum_types::U256;
     use std::fs;
 
     #[test]
@@ -93,6 +130,61 @@ mod tests {
         assert_block_is_correct(result)
     }
 
+    #[test]
+    fn should_deserialize_block_json_from_each_client_correctly() {
+        SAMPLE_CLIENT_NAMES.iter().for_each(|client| {
+            let block_json = fs::read_to_string(get_sample_client_block_json_path(client)).unwrap();
+            let block_rpc = deserialize_to_block_rpc_response(block_json).unwrap();
+            let result = deserialize_block_json_to_block_struct(block_rpc.result).unwrap();
+            assert_client_block_is_correct(result, client)
+        });
+    }
+
+    #[test]
+    fn should_use_miner_as_author_if_block_json_has_no_author() {
+        let block_json = fs::read_to_string(get_sample_client_block_json_path("geth")).unwrap();
+        let block_rpc = deserialize_to_block_rpc_response(block_json).unwrap();
+        assert!(block_rpc.result.author.is_none());
+        let result = deserialize_block_json_to_block_struct(block_rpc.result).unwrap();
+        assert!(result.author == result.miner);
+    }
+
+    #[test]
+    fn should_have_no_total_difficulty_if_block_json_has_none() {
+        let block_json = fs::read_to_string(get_sample_client_block_json_path("reth")).unwrap();
+        let block_rpc = deserialize_to_block_rpc_response(block_json).unwrap();
+        let result = deserialize_block_json_to_block_struct(block_rpc.result).unwrap();
+        assert!(result.total_difficulty.is_none());
+    }
+
+    #[test]
+    fn should_have_no_post_london_header_fields_in_pre_london_block() {
+        let block = get_expected_block();
+        assert!(block.base_fee_per_gas.is_none());
+        assert!(block.withdrawals_root.is_none());
+        assert!(block.blob_gas_used.is_none());
+        assert!(block.excess_blob_gas.is_none());
+        assert!(block.parent_beacon_block_root.is_none());
+        assert!(block.requests_hash.is_none());
+    }
+
+    #[test]
+    fn should_deserialize_post_london_header_fields_from_prague_block_json() {
+        let parent_beacon_root =
+            "0x7f2d6a7e4ec1e3f1c04f5a3a0a58e1d4b08c1f3cd4b0f3b3e53ad7b1ce2d8a19".to_string();
+        let requests_hash =
+            "0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string();
+        let block_json = fs::read_to_string(SAMPLE_PRAGUE_BLOCK_JSON_PATH).unwrap();
+        let block_rpc = deserialize_to_block_rpc_response(block_json).unwrap();
+        let result = deserialize_block_json_to_block_struct(block_rpc.result).unwrap();
+        assert!(result.base_fee_per_gas == Some(U256::from(0x1e0b5f2d7u64)));
+        assert!(result.withdrawals_root == Some(HASHED_NULL_NODE));
+        assert!(result.blob_gas_used == Some(U256::from(0x60000)));
+        assert!(result.excess_blob_gas == Some(U256::from(0x4b00000)));
+        assert!(result.parent_beacon_block_root == convert_hex_to_h256(parent_beacon_root).ok());
+        assert!(result.requests_hash == convert_hex_to_h256(requests_hash).ok());
+    }
+
     #[test]
     fn should_get_block_by_block_hash() {
         let result =
@@ -1,50 +1,342 @@
 use crate::{
+    constants::HASHED_NULL_NODE,
     errors::AppError,
     nibble_utils::get_nibbles_from_bytes,
-    nibble_utils::Nibbles,
+    nibble_utils::{
+        convert_nibble_to_usize, get_common_prefix_nibbles, split_at_first_nibble, Nibbles,
+    },
+    node_store::NodeStore,
     rlp_codec::rlp_encode_transaction_index,
     state::State,
-    trie::Trie,
-    types::{NodeStack, Result},
-    utils::convert_hex_to_u256,
+    trie::{put_in_trie_recursively, Trie},
+    trie_nodes::{is_inline_node_reference, rlp_decode_node, Node},
+    types::{Bytes, NodeStack, Result},
+    utils::{convert_bytes_to_h256, convert_hex_to_u256},
 };
+use ethereum_types::H256;
+use std::collections::HashMap;
 
-fn convert_usize_index_to_trie_key(index: usize) -> Result<Nibbles> {
+pub type KeyValueTuples = Vec<(Nibbles, Bytes)>;
+
+pub fn convert_usize_index_to_trie_key(index: usize) -> Result<Nibbles> {
     convert_hex_to_u256(hex::encode(index.to_be_bytes()))
         .and_then(|u256| rlp_encode_transaction_index(&u256))
         .map(get_nibbles_from_bytes)
 }
 
-pub fn get_branch_from_trie(receipts_trie: Trie, index: usize) -> Result<NodeStack> {
-    receipts_trie
-        .find(convert_usize_index_to_trie_key(index)?)
-        .and_then(
-            |(_, _, found_stack, remaining_key)| match remaining_key.len() {
-                0 => Ok(found_stack),
-                _ => Err(AppError::Custom(format!(
+fn get_branch_from_trie_at_index<D: NodeStore>(
+    trie: &Trie<D>,
+    index: usize,
+    item_name: &str,
+) -> Result<NodeStack> {
+    trie.find(convert_usize_index_to_trie_key(index)?).and_then(
+        |(_, found_stack, remaining_key)| match remaining_key.len() {
+            0 => Ok(found_stack),
+            _ => Err(AppError::Custom(format!(
+                "✘ Error! No {} in trie at given index: {}",
+                item_name, index
+            ))),
+        },
+    )
+}
+
+/**
+ *
+ * Getting an Exclusion Branch:
+ *
+ * Finding a key absent from the trie still walks as far as the key agrees
+ * with the trie, stopping at the node where it diverges. That path is what
+ * proves the key's absence. An empty trie has no nodes at all, and so its
+ * exclusion branch is empty too.
+ *
+ */
+fn get_exclusion_branch_from_trie_at_index<D: NodeStore>(
+    trie: &Trie<D>,
+    index: usize,
+    item_name: &str,
+) -> Result<NodeStack> {
+    let key = convert_usize_index_to_trie_key(index)?;
+    match (trie.root == HASHED_NULL_NODE, trie.get(key.clone())?) {
+        (true, _) => Ok(Vec::new()),
+        (false, Some(_)) => Err(AppError::Custom(format!(
+            "✘ Error! Cannot prove exclusion - {} in trie at given index: {}",
+            item_name, index
+        ))),
+        (false, None) => trie.find(key).map(|(_, found_stack, _)| found_stack),
+    }
+}
+
+pub fn get_branch_from_trie<D: NodeStore>(
+    receipts_trie: &Trie<D>,
+    index: usize,
+) -> Result<NodeStack> {
+    get_branch_from_trie_at_index(receipts_trie, index, "receipt")
+}
+
+pub fn get_branches_from_trie<D: NodeStore>(
+    receipts_trie: &Trie<D>,
+    indices: &[usize],
+) -> Result<NodeStack> {
+    match indices.is_empty() {
+        true => Err(AppError::Custom(
+            "✘ Error! Cannot get branches for no indices!".to_string(),
+        )),
+        false => indices
+            .iter()
+            .try_fold(Vec::new(), |mut nodes: NodeStack, index| {
+                get_branch_from_trie_at_index(receipts_trie, *index, "receipt").map(|branch| {
+                    branch.into_iter().for_each(|node| {
+                        if !nodes.contains(&node) {
+                            nodes.push(node)
+                        }
+                    });
+                    nodes
+                })
+            }),
+    }
+}
+
+pub fn get_exclusion_branch_from_trie<D: NodeStore>(
+    receipts_trie: &Trie<D>,
+    index: usize,
+) -> Result<NodeStack> {
+    get_exclusion_branch_from_trie_at_index(receipts_trie, index, "receipt")
+}
+
+pub fn get_transaction_branch_from_trie<D: NodeStore>(
+    transactions_trie: &Trie<D>,
+    index: usize,
+) -> Result<NodeStack> {
+    get_branch_from_trie_at_index(transactions_trie, index, "transaction")
+}
+
+pub fn get_transaction_exclusion_branch_from_trie<D: NodeStore>(
+    transactions_trie: &Trie<D>,
+    index: usize,
+) -> Result<NodeStack> {
+    get_exclusion_branch_from_trie_at_index(transactions_trie, index, "transaction")
+}
+/**
+ *
+ * Getting Transaction Count Branches:
+ *
+ * A block's transactions are keyed by their contiguous indices from zero, so
+ * the inclusion of index `count - 1` together with the exclusion of index
+ * `count` fixes the block's exact transaction count. A block with no
+ * transactions needs only the exclusion of index zero, so its inclusion
+ * branch is empty.
+ *
+ */
+pub fn get_transaction_count_branches_from_trie<D: NodeStore>(
+    transactions_trie: &Trie<D>,
+    count: usize,
+) -> Result<(NodeStack, NodeStack)> {
+    Ok((
+        match count {
+            0 => Vec::new(),
+            _ => get_transaction_branch_from_trie(transactions_trie, count - 1)?,
+        },
+        get_transaction_exclusion_branch_from_trie(transactions_trie, count)?,
+    ))
+}
+
+pub fn get_root_from_key_value_tuples(key_value_tuples: KeyValueTuples) -> Result<H256> {
+    put_in_trie_recursively(Trie::get_new_trie()?, key_value_tuples, 0).map(|trie| trie.root)
+}
+/**
+ *
+ * Splitting Key-Values at a Node:
+ *
+ * Given a node and the key-values beneath it, keyed by what remains of their
+ * keys at that node, this checks any key the node itself holds has the
+ * expected value, then hands the rest down to the node's children, keyed by
+ * what remains of them there & paired with each child's reference. A key
+ * with no way onward is not in the trie.
+ *
+ */
+pub fn split_key_value_tuples_at_node(
+    node: &Node,
+    key_value_tuples: KeyValueTuples,
+) -> Result<Vec<(Bytes, KeyValueTuples)>> {
+    let key_not_in_trie_err =
+        || AppError::Custom("✘ Proof Error: Range contains a key not in the trie!".to_string());
+    let value_not_in_trie_err =
+        || AppError::Custom("✘ Proof Error: Range contains a value not in the trie!".to_string());
+    match node {
+        Node::Null => match key_value_tuples.is_empty() {
+            true => Ok(Vec::new()),
+            false => Err(key_not_in_trie_err()),
+        },
+        Node::Leaf(leaf) => key_value_tuples
+            .into_iter()
+            .try_for_each(
+                |(key, value)| match (key == leaf.path_nibbles, value == leaf.value) {
+                    (true, true) => Ok(()),
+                    (true, false) => Err(value_not_in_trie_err()),
+                    _ => Err(key_not_in_trie_err()),
+                },
+            )
+            .map(|_| Vec::new()),
+        Node::Extension(extension) => key_value_tuples
+            .into_iter()
+            .map(|(key, value)| {
+                get_common_prefix_nibbles(key, extension.path_nibbles.clone()).and_then(
+                    |(_, key_remainder, node_key_remainder)| match node_key_remainder.len() {
+                        0 => Ok((key_remainder, value)),
+                        _ => Err(key_not_in_trie_err()),
+                    },
+                )
+            })
+            .collect::<Result<KeyValueTuples>>()
+            .map(|child_key_value_tuples| vec![(extension.value.clone(), child_key_value_tuples)]),
+        Node::Branch(branch) => {
+            let mut children: Vec<KeyValueTuples> = vec![Vec::new(); 16];
+            for (key, value) in key_value_tuples {
+                match key.len() {
+                    0 => match Some(value) == branch.value {
+                        true => Ok(()),
+                        false => Err(value_not_in_trie_err()),
+                    }?,
+                    _ => {
+                        let (first_nibble, remaining_nibbles) = split_at_first_nibble(&key)?;
+                        children[convert_nibble_to_usize(first_nibble)]
+                            .push((remaining_nibbles, value))
+                    }
+                }
+            }
+            children
+                .into_iter()
+                .enumerate()
+                .filter(|(_, child_key_value_tuples)| !child_key_value_tuples.is_empty())
+                .map(|(i, child_key_value_tuples)| match &branch.branches[i] {
+                    Some(child_reference) => Ok((child_reference.clone(), child_key_value_tuples)),
+                    None => Err(key_not_in_trie_err()),
+                })
+                .collect()
+        }
+    }
+}
+/**
+ *
+ * Getting a Range Branch:
+ *
+ * Starting from the union of the branches of every key in the range, each
+ * node is kept only if the range's key-values beneath it do not rebuild it
+ * by themselves. A subtree lying wholly within the range is left for the
+ * verifier to rebuild, and it is exactly that rebuilding which shows the
+ * range omits nothing. So only the nodes straddling the range's edges make
+ * up the proof. Inline nodes travel within their parents, so are walked
+ * through but never added to the proof themselves.
+ *
+ */
+fn get_range_nodes_from_reference(
+    nodes_by_hash: &HashMap<H256, Node>,
+    reference: Bytes,
+    key_value_tuples: KeyValueTuples,
+) -> Result<NodeStack> {
+    let (node, nodes) = match is_inline_node_reference(&reference) {
+        true => (rlp_decode_node(reference)?, Vec::new()),
+        false => {
+            let hash = convert_bytes_to_h256(&reference)?;
+            if get_root_from_key_value_tuples(key_value_tuples.clone())? == hash {
+                return Ok(Vec::new());
+            };
+            let node = nodes_by_hash.get(&hash).ok_or_else(|| {
+                AppError::Custom(format!("✘ Error! Range branch is missing node {:?}!", hash))
+            })?;
+            (node.clone(), vec![node.clone()])
+        }
+    };
+    split_key_value_tuples_at_node(&node, key_value_tuples)?
+        .into_iter()
+        .try_fold(
+            nodes,
+            |mut nodes, (child_reference, child_key_value_tuples)| {
+                nodes.append(&mut get_range_nodes_from_reference(
+                    nodes_by_hash,
+                    child_reference,
+                    child_key_value_tuples,
+                )?);
+                Ok(nodes)
+            },
+        )
+}
+
+pub fn get_range_branch_from_trie<D: NodeStore>(
+    receipts_trie: &Trie<D>,
+    first_index: usize,
+    last_index: usize,
+) -> Result<(NodeStack, Vec<Bytes>)> {
+    if first_index > last_index {
+        return Err(AppError::Custom(format!(
+            "✘ Error! Range's first index {} is after its last index {}!",
+            first_index, last_index
+        )));
+    };
+    let indices = (first_index..=last_index).collect::<Vec<usize>>();
+    let key_value_tuples = indices
+        .iter()
+        .map(|index| {
+            let key = convert_usize_index_to_trie_key(*index)?;
+            match receipts_trie.get(key.clone())? {
+                Some(value) => Ok((key, value)),
+                None => Err(AppError::Custom(format!(
                     "✘ Error! No receipt in trie at given index: {}",
                     index
                 ))),
-            },
-        )
+            }
+        })
+        .collect::<Result<KeyValueTuples>>()?;
+    let nodes_by_hash = get_branches_from_trie(receipts_trie, &indices)?
+        .into_iter()
+        .map(|node| Ok((node.get_hash()?, node)))
+        .collect::<Result<HashMap<H256, Node>>>()?;
+    Ok((
+        get_range_nodes_from_reference(
+            &nodes_by_hash,
+            receipts_trie.root.as_bytes().to_vec(),
+            key_value_tuples.clone(),
+        )?,
+        key_value_tuples
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect(),
+    ))
 }
 
 pub fn get_branch_from_trie_and_put_in_state(state: State) -> Result<State> {
     info!("✔ Pulling branch from trie...");
     get_branch_from_trie(
-        state.get_receipts_trie_from_state()?.clone(),
+        state.get_receipts_trie_from_state()?,
         *state.get_index_from_state()?,
     )
     .and_then(|branch| state.set_branch_in_state(branch))
 }
 
+pub fn get_transaction_branch_from_trie_and_put_in_state(state: State) -> Result<State> {
+    info!("✔ Pulling transaction branch from trie...");
+    get_transaction_branch_from_trie(
+        state.get_transactions_trie_from_state()?,
+        *state.get_index_from_state()?,
+    )
+    .and_then(|branch| state.set_transaction_branch_in_state(branch))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::get_transactions_trie::get_transactions_trie_from_transactions;
+    use crate::node_store::FileNodeStore;
+    use crate::rlp_codec::get_rlp_encoded_receipts_and_nibble_tuples;
     use crate::test_utils::{
-        get_sample_trie_with_sample_receipts, get_sample_tx_hashes_1,
-        get_valid_state_with_receipts_trie_and_index, SAMPLE_RECEIPT_JSONS_1_PATH,
+        get_sample_extension_node, get_sample_indexed_value, get_sample_leaf_node,
+        get_sample_receipts, get_sample_transactions, get_sample_trie_with_indexed_values,
+        get_sample_trie_with_sample_receipts, get_sample_tx_hashes_1, get_sample_tx_hashes_2,
+        get_sample_tx_hashes_3, get_temporary_directory,
+        get_valid_state_with_receipts_trie_and_index, get_valid_state_with_transactions_trie,
+        SAMPLE_RECEIPT_JSONS_1_PATH, SAMPLE_RECEIPT_JSONS_2_PATH, SAMPLE_RECEIPT_JSONS_3_PATH,
     };
+    use std::time::Instant;
 
     #[test]
     fn should_convert_usize_to_trie_key() {
@@ -64,7 +356,7 @@ mod tests {
             SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
             get_sample_tx_hashes_1(),
         );
-        get_branch_from_trie(trie, index).unwrap();
+        get_branch_from_trie(&trie, index).unwrap();
     }
 
     #[test]
@@ -78,12 +370,272 @@ mod tests {
             SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
             get_sample_tx_hashes_1(),
         );
-        match get_branch_from_trie(trie, non_existent_index) {
+        match get_branch_from_trie(&trie, non_existent_index) {
             Err(AppError::Custom(e)) => assert!(e == expected_error),
             _ => panic!("Getting branch should not have succeeded!"),
         }
     }
 
+    #[test]
+    fn should_get_same_nodes_from_branches_of_single_index_as_from_branch() {
+        let index = 14;
+        let trie = get_sample_trie_with_sample_receipts(
+            SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+            get_sample_tx_hashes_1(),
+        );
+        let expected_result = get_branch_from_trie(&trie, index).unwrap();
+        let result = get_branches_from_trie(&trie, &[index]).unwrap();
+        assert!(result == expected_result);
+    }
+
+    #[test]
+    fn should_get_same_branch_from_trie_reopened_from_file_store() {
+        let index = 14;
+        let directory = get_temporary_directory("branch-from-file-store");
+        let trie = get_sample_trie_with_sample_receipts(
+            SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+            get_sample_tx_hashes_1(),
+        );
+        let mut store = FileNodeStore::new(&directory).unwrap();
+        trie.database
+            .nodes
+            .iter()
+            .for_each(|(hash, encoded_node)| store.put(*hash, encoded_node.clone()).unwrap());
+        let reopened_trie = Trie::open_trie_at_root(trie.root, store).unwrap();
+        let expected_result = get_branch_from_trie(&trie, index).unwrap();
+        let result = get_branch_from_trie(&reopened_trie, index).unwrap();
+        assert!(result == expected_result);
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_get_deduplicated_union_of_branches_from_trie() {
+        let indices = [0, 3, 14, 15];
+        let trie = get_sample_trie_with_sample_receipts(
+            SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+            get_sample_tx_hashes_1(),
+        );
+        let branches = indices
+            .iter()
+            .map(|index| get_branch_from_trie(&trie, *index).unwrap())
+            .collect::<Vec<NodeStack>>();
+        let result = get_branches_from_trie(&trie, &indices).unwrap();
+        assert!(result[0] == branches[0][0]);
+        assert!(result.len() < branches.iter().map(|branch| branch.len()).sum());
+        branches
+            .iter()
+            .flatten()
+            .for_each(|node| assert!(result.iter().filter(|n| n == &node).count() == 1));
+    }
+
+    #[test]
+    fn should_fail_to_get_branches_for_no_indices() {
+        let expected_error = "✘ Error! Cannot get branches for no indices!";
+        let trie = get_sample_trie_with_sample_receipts(
+            SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+            get_sample_tx_hashes_1(),
+        );
+        match get_branches_from_trie(&trie, &[]) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Getting branches should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_get_branches_if_any_index_not_in_trie() {
+        let non_existent_index = get_sample_tx_hashes_1().len() + 1;
+        let expected_error = format!(
+            "✘ Error! No receipt in trie at given index: {}",
+            non_existent_index
+        );
+        let trie = get_sample_trie_with_sample_receipts(
+            SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+            get_sample_tx_hashes_1(),
+        );
+        match get_branches_from_trie(&trie, &[0, non_existent_index]) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Getting branches should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_get_exclusion_branch_from_trie() {
+        let num_receipts = get_sample_tx_hashes_1().len();
+        let trie = get_sample_trie_with_sample_receipts(
+            SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+            get_sample_tx_hashes_1(),
+        );
+        let result = get_exclusion_branch_from_trie(&trie, num_receipts).unwrap();
+        let inclusion_branch = get_branch_from_trie(&trie, num_receipts - 1).unwrap();
+        assert!(!result.is_empty());
+        assert!(result[0] == inclusion_branch[0]);
+    }
+
+    #[test]
+    fn should_get_empty_exclusion_branch_from_empty_trie() {
+        let result = get_exclusion_branch_from_trie(&Trie::get_new_trie().unwrap(), 0).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn should_fail_to_get_exclusion_branch_for_index_in_trie() {
+        let index = 14;
+        let expected_error = format!(
+            "✘ Error! Cannot prove exclusion - receipt in trie at given index: {}",
+            index
+        );
+        let trie = get_sample_trie_with_sample_receipts(
+            SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+            get_sample_tx_hashes_1(),
+        );
+        match get_exclusion_branch_from_trie(&trie, index) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Getting exclusion branch should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_get_transaction_count_branches_from_trie() {
+        let transactions = get_sample_transactions();
+        let count = transactions.len();
+        let trie = get_transactions_trie_from_transactions(&transactions).unwrap();
+        let (inclusion_branch, exclusion_branch) =
+            get_transaction_count_branches_from_trie(&trie, count).unwrap();
+        assert!(inclusion_branch == get_transaction_branch_from_trie(&trie, count - 1).unwrap());
+        assert!(!exclusion_branch.is_empty());
+    }
+
+    #[test]
+    fn should_get_transaction_count_branches_from_empty_trie() {
+        let trie = get_transactions_trie_from_transactions(&[]).unwrap();
+        let (inclusion_branch, exclusion_branch) =
+            get_transaction_count_branches_from_trie(&trie, 0).unwrap();
+        assert!(inclusion_branch.is_empty());
+        assert!(exclusion_branch.is_empty());
+    }
+
+    #[test]
+    fn should_fail_to_get_transaction_count_branches_for_wrong_count() {
+        let transactions = get_sample_transactions();
+        let trie = get_transactions_trie_from_transactions(&transactions).unwrap();
+        assert!(get_transaction_count_branches_from_trie(&trie, transactions.len() - 1).is_err());
+        assert!(get_transaction_count_branches_from_trie(&trie, transactions.len() + 1).is_err());
+    }
+
+    #[test]
+    fn should_get_root_from_key_value_tuples() {
+        let trie = get_sample_trie_with_indexed_values(300);
+        let key_value_tuples = (0..300)
+            .map(|index| {
+                (
+                    convert_usize_index_to_trie_key(index).unwrap(),
+                    get_sample_indexed_value(index),
+                )
+            })
+            .collect();
+        let result = get_root_from_key_value_tuples(key_value_tuples).unwrap();
+        assert!(result == trie.root);
+    }
+
+    #[test]
+    fn should_split_key_value_tuples_at_branch_node() {
+        let trie = get_sample_trie_with_indexed_values(300);
+        let key_value_tuples = vec![
+            (
+                convert_usize_index_to_trie_key(1).unwrap(),
+                get_sample_indexed_value(1),
+            ),
+            (
+                convert_usize_index_to_trie_key(2).unwrap(),
+                get_sample_indexed_value(2),
+            ),
+            (
+                convert_usize_index_to_trie_key(0x80).unwrap(),
+                get_sample_indexed_value(0x80),
+            ),
+        ];
+        let (_, branch, _) = trie.find(key_value_tuples[0].0.clone()).unwrap();
+        let result = split_key_value_tuples_at_node(&branch[0], key_value_tuples).unwrap();
+        assert!(branch[0].get_type() == "branch");
+        assert!(result.len() == 2);
+        assert!(result[0].1.len() == 2);
+        assert!(result[1].1.len() == 1);
+    }
+
+    #[test]
+    fn should_fail_to_split_key_value_tuples_with_wrong_value_at_leaf_node() {
+        let expected_error = "✘ Proof Error: Range contains a value not in the trie!";
+        let leaf_node = get_sample_leaf_node();
+        let key_value_tuples = vec![(leaf_node.get_key(), vec![0xde, 0xca, 0xf0])];
+        match split_key_value_tuples_at_node(&leaf_node, key_value_tuples) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Splitting key-values should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_split_key_value_tuples_diverging_from_extension_node() {
+        let expected_error = "✘ Proof Error: Range contains a key not in the trie!";
+        let key_value_tuples = vec![(get_nibbles_from_bytes(vec![0xc0, 0xfe]), vec![0xc0])];
+        match split_key_value_tuples_at_node(&get_sample_extension_node(), key_value_tuples) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Splitting key-values should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_get_range_branch_from_trie() {
+        let (first_index, last_index) = (3, 10);
+        let indices = (first_index..=last_index).collect::<Vec<usize>>();
+        let trie = get_sample_trie_with_sample_receipts(
+            SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+            get_sample_tx_hashes_1(),
+        );
+        let multiproof_nodes = get_branches_from_trie(&trie, &indices).unwrap();
+        let (nodes, values) = get_range_branch_from_trie(&trie, first_index, last_index).unwrap();
+        assert!(!nodes.is_empty());
+        assert!(nodes.len() < multiproof_nodes.len());
+        nodes
+            .iter()
+            .for_each(|node| assert!(multiproof_nodes.contains(node)));
+        indices
+            .iter()
+            .zip(values.iter())
+            .for_each(|(index, value)| {
+                let key = convert_usize_index_to_trie_key(*index).unwrap();
+                assert!(trie.get(key).unwrap() == Some(value.clone()))
+            });
+    }
+
+    #[test]
+    fn should_get_empty_range_branch_for_whole_trie() {
+        let num_values = 300;
+        let trie = get_sample_trie_with_indexed_values(num_values);
+        let (nodes, values) = get_range_branch_from_trie(&trie, 0, num_values - 1).unwrap();
+        assert!(nodes.is_empty());
+        assert!(values.len() == num_values);
+    }
+
+    #[test]
+    fn should_fail_to_get_range_branch_with_first_index_after_last() {
+        let expected_error = "✘ Error! Range's first index 5 is after its last index 4!";
+        let trie = get_sample_trie_with_indexed_values(10);
+        match get_range_branch_from_trie(&trie, 5, 4) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Getting range branch should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_get_range_branch_past_end_of_trie() {
+        let expected_error = "✘ Error! No receipt in trie at given index: 10";
+        let trie = get_sample_trie_with_indexed_values(10);
+        match get_range_branch_from_trie(&trie, 5, 12) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Getting range branch should not have succeeded!"),
+        }
+    }
+
     #[test]
     fn should_get_branch_and_put_in_state() {
         let trie = get_sample_trie_with_sample_receipts(
@@ -96,7 +648,7 @@ mod tests {
         )
         .unwrap();
         let index = state_before.get_index_from_state().unwrap();
-        let expected_branch = get_branch_from_trie(trie, *index).unwrap();
+        let expected_branch = get_branch_from_trie(&trie, *index).unwrap();
         if let Ok(_) = state_before.get_branch_from_state() {
             panic!("Should not have branch in state yet!")
         };
@@ -110,4 +662,100 @@ mod tests {
             }
         };
     }
+
+    #[test]
+    fn should_fail_to_get_non_existent_transaction_branch_from_trie_correctly() {
+        let non_existent_index = get_sample_transactions().len();
+        let expected_error = format!(
+            "✘ Error! No transaction in trie at given index: {}",
+            non_existent_index
+        );
+        let trie = get_transactions_trie_from_transactions(&get_sample_transactions()).unwrap();
+        match get_transaction_branch_from_trie(&trie, non_existent_index) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Getting transaction branch should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_get_transaction_branch_and_put_in_state() {
+        let index = 2;
+        let trie = get_transactions_trie_from_transactions(&get_sample_transactions()).unwrap();
+        let expected_branch = get_transaction_branch_from_trie(&trie, index).unwrap();
+        let state_before = get_valid_state_with_transactions_trie()
+            .and_then(|state| state.set_index_in_state(index))
+            .unwrap();
+        let state_after = get_transaction_branch_from_trie_and_put_in_state(state_before).unwrap();
+        let branch = state_after.get_transaction_branch_from_state().unwrap();
+        assert!(branch == &expected_branch);
+    }
+
+    fn get_mean_millis_of_runs<F: FnMut()>(num_runs: u32, mut run: F) -> f64 {
+        let start = Instant::now();
+        (0..num_runs).for_each(|_| run());
+        start.elapsed().as_secs_f64() * 1000.0 / f64::from(num_runs)
+    }
+
+    #[test]
+    #[ignore] // Timings only! Run w/ cargo test --release -- --ignored --nocapture
+    fn should_time_building_tries_and_getting_every_branch() {
+        let num_runs = 20;
+        let num_indexed_values = 1000;
+        let mut key_value_tuple_sets = vec![
+            (SAMPLE_RECEIPT_JSONS_1_PATH, get_sample_tx_hashes_1()),
+            (SAMPLE_RECEIPT_JSONS_2_PATH, get_sample_tx_hashes_2()),
+            (SAMPLE_RECEIPT_JSONS_3_PATH, get_sample_tx_hashes_3()),
+        ]
+        .into_iter()
+        .map(|(path, tx_hashes)| {
+            let receipts = get_sample_receipts(path.to_string(), tx_hashes);
+            (
+                path.to_string(),
+                get_rlp_encoded_receipts_and_nibble_tuples(&receipts).unwrap(),
+            )
+        })
+        .collect::<Vec<(String, Vec<(Nibbles, Bytes)>)>>();
+        key_value_tuple_sets.push((
+            format!("{} indexed values", num_indexed_values),
+            (0..num_indexed_values)
+                .map(|i| {
+                    (
+                        convert_usize_index_to_trie_key(i).unwrap(),
+                        get_sample_indexed_value(i),
+                    )
+                })
+                .collect(),
+        ));
+        println!("| Sample set | Values | Build trie (ms) | Get every branch (ms) |");
+        key_value_tuple_sets
+            .into_iter()
+            .for_each(|(name, key_value_tuples)| {
+                let build_millis = get_mean_millis_of_runs(num_runs, || {
+                    put_in_trie_recursively(
+                        Trie::get_new_trie().unwrap(),
+                        key_value_tuples.clone(),
+                        0,
+                    )
+                    .unwrap();
+                });
+                let trie = put_in_trie_recursively(
+                    Trie::get_new_trie().unwrap(),
+                    key_value_tuples.clone(),
+                    0,
+                )
+                .unwrap();
+                let branches_millis = get_mean_millis_of_runs(num_runs, || {
+                    (0..key_value_tuples.len()).for_each(|i| {
+                        get_branch_from_trie(&trie, i).unwrap();
+                    })
+                });
+                println!(
+                    "| {} | {} | {:.3} | {:.3} |",
+                    name,
+                    key_value_tuples.len(),
+                    build_millis,
+                    branches_millis
+                );
+            });
+    }
 }
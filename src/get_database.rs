@@ -1,24 +1,25 @@
-use crate::types::{Bytes, Database, Result};
+use crate::node_store::{MemoryNodeStore, NodeStore};
+use crate::types::{Bytes, Result};
 use ethereum_types::H256;
 
-pub fn get_new_database() -> Result<Database> {
-    Ok(std::collections::HashMap::new())
+pub fn get_new_database() -> Result<MemoryNodeStore> {
+    Ok(MemoryNodeStore::default())
 }
 
-pub fn put_thing_in_database(mut database: Database, key: H256, value: Bytes) -> Result<Database> {
-    database.insert(key, value);
-    Ok(database)
+pub fn put_thing_in_database<D: NodeStore>(
+    database: &mut D,
+    key: H256,
+    value: Bytes,
+) -> Result<()> {
+    database.put(key, value)
 }
 
-pub fn remove_thing_from_database(mut database: Database, key: &H256) -> Result<Database> {
-    match database.remove(key) {
-        Some(_) => Ok(database),
-        None => Ok(database),
-    }
+pub fn remove_thing_from_database<D: NodeStore>(database: &mut D, key: &H256) -> Result<()> {
+    database.remove(key)
 }
 
-pub fn get_thing_from_database(database: &Database, key: &H256) -> Option<Bytes> {
-    database.get(key).map(|thing| thing.to_vec())
+pub fn get_thing_from_database<D: NodeStore>(database: &D, key: &H256) -> Result<Option<Bytes>> {
+    database.get(key)
 }
 
 #[cfg(test)]
@@ -32,19 +33,16 @@ mod tests {
     #[test]
     fn should_get_new_empty_database() {
         let database = get_new_database().unwrap();
-        assert!(database.is_empty())
+        assert!(database.nodes.is_empty())
     }
 
     #[test]
     fn should_insert_thing_in_database() {
-        let database = get_new_database().unwrap();
+        let mut database = get_new_database().unwrap();
+        let key = get_expected_key_of_thing_in_database();
         let expected_result = get_thing_to_put_in_database();
-        put_thing_in_database(
-            database,
-            get_expected_key_of_thing_in_database(),
-            expected_result.clone(),
-        )
-        .unwrap();
+        put_thing_in_database(&mut database, key, expected_result.clone()).unwrap();
+        assert!(get_thing_from_database(&database, &key).unwrap() == Some(expected_result));
     }
 
     #[test]
@@ -52,18 +50,17 @@ mod tests {
         let expected_thing = get_thing_to_put_in_database();
         let database = get_database_with_thing_in_it().unwrap();
         let key = get_expected_key_of_thing_in_database();
-        let result = get_thing_from_database(&database, &key).unwrap();
+        let result = get_thing_from_database(&database, &key).unwrap().unwrap();
         assert!(result == expected_thing);
     }
 
     #[test]
     fn should_remove_thing_from_database() {
         let key = get_expected_key_of_thing_in_database();
-        let database = get_new_database().unwrap();
-        let updated_database =
-            put_thing_in_database(database, key.clone(), get_thing_to_put_in_database()).unwrap();
-        assert!(updated_database.contains_key(&key));
-        let result = remove_thing_from_database(updated_database, &key).unwrap();
-        assert!(!result.contains_key(&key));
+        let mut database = get_new_database().unwrap();
+        put_thing_in_database(&mut database, key, get_thing_to_put_in_database()).unwrap();
+        assert!(database.contains(&key).unwrap());
+        remove_thing_from_database(&mut database, &key).unwrap();
+        assert!(!database.contains(&key).unwrap());
     }
 }
@@ -13,7 +13,7 @@ fn maybe_run_dot_env() -> Result<()> {
     }
 }
 
-fn get_endpoint_from_env_vars() -> Result<String> {
+pub fn get_endpoint_from_env_vars() -> Result<String> {
     maybe_run_dot_env().map(|_| match std::env::var("ENDPOINT") {
         Ok(endpoint) => endpoint,
         Err(_) => DEFAULT_ENDPOINT.to_string(),
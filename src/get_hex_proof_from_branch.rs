@@ -1,26 +1,40 @@
 use crate::{
+    rlp_codec::rlp_encode_block_header,
     state::State,
     trie_nodes::Node,
-    types::{Bytes, HexProof, Result},
+    types::{Block, Bytes, HexProof, Result},
     utils::convert_bytes_to_hex,
 };
 use rlp::RlpStream;
 
-fn rlp_encode_node_stack(node_stack: &[Node]) -> Result<Bytes> {
-    let mut rlp_stream = RlpStream::new();
-    rlp_stream.begin_list(node_stack.len());
-    node_stack
-        .iter()
-        .map(|node| node.get_rlp_encoding())
-        .collect::<Result<Vec<_>>>()?
+/**
+ * Stacks of nodes are ordered root first. A node under 32 bytes long is
+ * embedded inline in its parent, and so already travels within it. Hence
+ * only the root, which is always referred to by its hash, plus the nodes
+ * whose parents refer to them by hash are encoded into the proof.
+ *
+ */
+pub fn rlp_encode_node_stack(node_stack: &[Node]) -> Result<Bytes> {
+    let rlp_encoded_nodes = node_stack
         .iter()
-        .for_each(|rlp_encoded_node| {
-            rlp_stream.append_raw(rlp_encoded_node, 1);
-        });
+        .enumerate()
+        .map(|(i, node)| Ok((i == 0 || !node.is_inline()?, node.get_rlp_encoding()?)))
+        .collect::<Result<Vec<(bool, Bytes)>>>()?
+        .into_iter()
+        .filter_map(|(is_hashed, rlp_encoded_node)| match is_hashed {
+            true => Some(rlp_encoded_node),
+            false => None,
+        })
+        .collect::<Vec<Bytes>>();
+    let mut rlp_stream = RlpStream::new();
+    rlp_stream.begin_list(rlp_encoded_nodes.len());
+    rlp_encoded_nodes.iter().for_each(|rlp_encoded_node| {
+        rlp_stream.append_raw(rlp_encoded_node, 1);
+    });
     Ok(rlp_stream.out())
 }
 
-fn get_hex_proof_from_branch(branch: &[Node]) -> Result<HexProof> {
+pub fn get_hex_proof_from_branch(branch: &[Node]) -> Result<HexProof> {
     rlp_encode_node_stack(branch).map(convert_bytes_to_hex)
 }
 
@@ -31,16 +45,55 @@ pub fn get_hex_proof_from_branch_in_state(state: State) -> Result<HexProof> {
         .and_then(|node_stack| get_hex_proof_from_branch(node_stack))
 }
 
+fn get_hex_proof_with_header(block: &Block, branch: &[Node]) -> Result<String> {
+    Ok(json!({
+        "header": convert_bytes_to_hex(rlp_encode_block_header(block)?),
+        "proof": get_hex_proof_from_branch(branch)?,
+    })
+    .to_string())
+}
+
+pub fn get_hex_proof_with_header_from_state(state: State) -> Result<String> {
+    info!("✔ Hex encoding proof & RLP-encoded block header...");
+    get_hex_proof_with_header(
+        state.get_block_from_state()?,
+        state.get_branch_from_state()?,
+    )
+}
+
+pub fn get_hex_proof_from_transaction_branch_in_state(state: State) -> Result<HexProof> {
+    info!("✔ Hex encoding proof from nodes in transaction branch...");
+    state
+        .get_transaction_branch_from_state()
+        .and_then(|node_stack| get_hex_proof_from_branch(node_stack))
+}
+
+pub fn get_hex_transaction_proof_with_header_from_state(state: State) -> Result<String> {
+    info!("✔ Hex encoding transaction proof & RLP-encoded block header...");
+    get_hex_proof_with_header(
+        state.get_block_from_state()?,
+        state.get_transaction_branch_from_state()?,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::get_branch_from_trie::get_branch_from_trie;
+    use crate::get_branch_from_trie::{
+        get_branch_from_trie, get_transaction_branch_from_trie_and_put_in_state,
+    };
+    use crate::get_keccak_hash::keccak_hash_bytes;
+    use crate::nibble_utils::get_nibbles_from_bytes;
     use crate::test_utils::{
-        get_sample_proof_1, get_sample_proof_3, get_sample_trie_with_sample_receipts,
+        get_expected_block, get_sample_proof_1, get_sample_proof_3, get_sample_transactions,
+        get_sample_trie_with_inline_nodes, get_sample_trie_with_sample_receipts,
         get_sample_tx_hashes_1, get_sample_tx_hashes_3,
-        get_valid_state_with_receipts_trie_index_and_branch, PROOF_1_INDEX, PROOF_3_INDEX,
-        SAMPLE_RECEIPT_JSONS_1_PATH, SAMPLE_RECEIPT_JSONS_3_PATH,
+        get_valid_state_with_receipts_trie_index_and_branch,
+        get_valid_state_with_transactions_trie, PROOF_1_INDEX, PROOF_3_INDEX,
+        SAMPLE_RECEIPT_JSONS_1_PATH, SAMPLE_RECEIPT_JSONS_3_PATH, TRANSACTIONS_ROOT,
     };
+    use crate::utils::convert_hex_to_h256;
+    use crate::verify_proof::verify_transaction_proof;
 
     #[test]
     fn should_get_hex_proof_1_from_branch() {
@@ -49,7 +102,7 @@ mod tests {
             SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
             get_sample_tx_hashes_1(),
         );
-        let branch = get_branch_from_trie(trie, PROOF_1_INDEX).unwrap();
+        let branch = get_branch_from_trie(&trie, PROOF_1_INDEX).unwrap();
         let result = get_hex_proof_from_branch(&branch).unwrap();
         assert!(result == expected_result);
     }
@@ -61,7 +114,18 @@ mod tests {
             SAMPLE_RECEIPT_JSONS_3_PATH.to_string(),
             get_sample_tx_hashes_3(),
         );
-        let branch = get_branch_from_trie(trie, PROOF_3_INDEX).unwrap();
+        let branch = get_branch_from_trie(&trie, PROOF_3_INDEX).unwrap();
+        let result = get_hex_proof_from_branch(&branch).unwrap();
+        assert!(result == expected_result);
+    }
+
+    #[test]
+    fn should_leave_inline_nodes_out_of_hex_proof() {
+        let expected_result = "f8bee216a0bd3ee507e6c67cfefca98f84be47c1bbc009315fabc4405db4ba32190374572af84080808080a094a9f95bd89698e4da1812e0518053813b4d5b87caaf6b3c6fa57e9e50c0ff68808080cf85206f727365887374616c6c696f6e8080808080808080e482006fa0d43b87fdcd4217013ccc92d04662e12d36e4cc25dc690077cd821a1956fc3e36f3808080808080de17dc808080808080c63584636f696e8080808080808080808570757070798080808080808080808476657262";
+        let trie = get_sample_trie_with_inline_nodes();
+        let key = get_nibbles_from_bytes(b"dog".to_vec());
+        let (_, branch, _) = trie.find(key).unwrap();
+        assert!(branch.len() == 6);
         let result = get_hex_proof_from_branch(&branch).unwrap();
         assert!(result == expected_result);
     }
@@ -77,4 +141,55 @@ mod tests {
         let result = get_hex_proof_from_branch_in_state(state).unwrap();
         assert!(result == expected_result);
     }
+
+    #[test]
+    fn should_get_hex_proof_with_header_from_state() {
+        let block = get_expected_block();
+        let expected_header = convert_bytes_to_hex(rlp_encode_block_header(&block).unwrap());
+        let state = get_valid_state_with_receipts_trie_index_and_branch(
+            SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+            get_sample_tx_hashes_1(),
+        )
+        .and_then(|state| state.set_block_in_state(block))
+        .unwrap();
+        let result = get_hex_proof_with_header_from_state(state).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert!(json["proof"] == get_sample_proof_1());
+        assert!(json["header"] == expected_header);
+    }
+
+    #[test]
+    fn should_get_verifiable_hex_proof_from_transaction_branch_in_state() {
+        let transactions_root = convert_hex_to_h256(TRANSACTIONS_ROOT.to_string()).unwrap();
+        get_sample_transactions()
+            .iter()
+            .enumerate()
+            .for_each(|(index, tx)| {
+                let hex_proof = get_valid_state_with_transactions_trie()
+                    .and_then(|state| state.set_index_in_state(index))
+                    .and_then(get_transaction_branch_from_trie_and_put_in_state)
+                    .and_then(get_hex_proof_from_transaction_branch_in_state)
+                    .unwrap();
+                let proven_tx =
+                    verify_transaction_proof(&hex_proof, &transactions_root, index).unwrap();
+                assert!(keccak_hash_bytes(&proven_tx).unwrap() == tx.hash);
+            });
+    }
+
+    #[test]
+    fn should_get_hex_transaction_proof_with_header_from_state() {
+        let block = get_expected_block();
+        let expected_header = convert_bytes_to_hex(rlp_encode_block_header(&block).unwrap());
+        let state = get_valid_state_with_transactions_trie()
+            .and_then(|state| state.set_index_in_state(1))
+            .and_then(get_transaction_branch_from_trie_and_put_in_state)
+            .and_then(|state| state.set_block_in_state(block))
+            .unwrap();
+        let expected_proof =
+            get_hex_proof_from_branch(state.get_transaction_branch_from_state().unwrap()).unwrap();
+        let result = get_hex_transaction_proof_with_header_from_state(state).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert!(json["proof"] == expected_proof);
+        assert!(json["header"] == expected_header);
+    }
 }
@@ -0,0 +1,140 @@
+use crate::check_block_hash::check_block_hash_in_state;
+use crate::check_receipts_root::check_receipts_root_in_state;
+use crate::check_transactions_root::check_transactions_root_in_state;
+use crate::get_block::get_block_from_tx_hash_in_state_and_set_in_state;
+use crate::get_branch_from_trie::{
+    get_branch_from_trie_and_put_in_state, get_transaction_branch_from_trie_and_put_in_state,
+};
+use crate::get_hex_proof_from_branch::get_hex_proof_from_branch;
+use crate::get_receipts::get_all_receipts_from_block_in_state_and_set_in_state;
+use crate::get_receipts_trie::get_receipts_trie_and_set_in_state;
+use crate::get_transactions::get_all_transactions_from_block_in_state_and_set_in_state;
+use crate::get_transactions_trie::get_transactions_trie_and_set_in_state;
+use crate::get_tx_index::get_tx_index_and_add_to_state;
+use crate::rlp_codec::{rlp_encode_block_header, rlp_encode_transaction_index};
+use crate::state::State;
+use crate::types::Result;
+use crate::utils::{convert_bytes_to_hex, convert_hex_to_h256};
+use crate::validate_tx_hash::validate_tx_hash;
+use ethereum_types::U256;
+
+pub fn get_proof_bundle_from_state(state: State) -> Result<String> {
+    info!("✔ Bundling header, transaction proof & receipt proof...");
+    let key = rlp_encode_transaction_index(&U256::from(*state.get_index_from_state()?))?;
+    Ok(json!({
+        "header": convert_bytes_to_hex(rlp_encode_block_header(state.get_block_from_state()?)?),
+        "key": convert_bytes_to_hex(key),
+        "transactionProof": get_hex_proof_from_branch(state.get_transaction_branch_from_state()?)?,
+        "receiptProof": get_hex_proof_from_branch(state.get_branch_from_state()?)?,
+    })
+    .to_string())
+}
+
+pub fn get_proof_bundle_for_tx_hash_in_state(state: State) -> Result<String> {
+    get_block_from_tx_hash_in_state_and_set_in_state(state)
+        .and_then(check_block_hash_in_state)
+        .and_then(get_tx_index_and_add_to_state)
+        .and_then(get_all_transactions_from_block_in_state_and_set_in_state)
+        .and_then(get_transactions_trie_and_set_in_state)
+        .and_then(check_transactions_root_in_state)
+        .and_then(get_transaction_branch_from_trie_and_put_in_state)
+        .and_then(get_all_receipts_from_block_in_state_and_set_in_state)
+        .and_then(get_receipts_trie_and_set_in_state)
+        .and_then(check_receipts_root_in_state)
+        .and_then(get_branch_from_trie_and_put_in_state)
+        .and_then(get_proof_bundle_from_state)
+}
+
+pub fn get_proof_bundle_from_tx_hash(endpoint: &str, tx_hash: &str) -> Result<String> {
+    validate_tx_hash(tx_hash.to_string())
+        .and_then(|_| {
+            State::init(
+                convert_hex_to_h256(tx_hash.to_string())?,
+                tx_hash.to_string(),
+            )
+        })
+        .and_then(|state| state.set_endpoint_in_state(endpoint.to_string()))
+        .and_then(get_proof_bundle_for_tx_hash_in_state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::AppError;
+    use crate::get_receipts_trie::get_receipts_trie_from_receipts;
+    use crate::test_utils::{
+        get_expected_block, get_sample_receipts, get_sample_tx_hashes_1,
+        get_valid_state_with_transactions_trie, RECEIPTS_ROOT_1, SAMPLE_RECEIPT_JSONS_1_PATH,
+        SAMPLE_TX_HASH, TRANSACTIONS_ROOT, WORKING_ENDPOINT,
+    };
+    use crate::verify_proof::{verify_receipt_proof, verify_transaction_proof};
+
+    fn get_state_with_both_branches(index: usize) -> State {
+        let receipts_trie = get_receipts_trie_from_receipts(&get_sample_receipts(
+            SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+            get_sample_tx_hashes_1(),
+        ))
+        .unwrap();
+        get_valid_state_with_transactions_trie()
+            .and_then(|state| state.set_receipts_trie_in_state(receipts_trie))
+            .and_then(|state| state.set_index_in_state(index))
+            .and_then(|state| state.set_block_in_state(get_expected_block()))
+            .and_then(get_transaction_branch_from_trie_and_put_in_state)
+            .and_then(get_branch_from_trie_and_put_in_state)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_get_proof_bundle_from_state() {
+        let index = 3;
+        let expected_header =
+            convert_bytes_to_hex(rlp_encode_block_header(&get_expected_block()).unwrap());
+        let result = get_proof_bundle_from_state(get_state_with_both_branches(index)).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let tx_proof = json["transactionProof"].as_str().unwrap();
+        let receipt_proof = json["receiptProof"].as_str().unwrap();
+        let transactions_root = convert_hex_to_h256(TRANSACTIONS_ROOT.to_string()).unwrap();
+        let receipts_root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        assert!(json["key"] == "03");
+        assert!(json["header"] == expected_header);
+        verify_transaction_proof(tx_proof, &transactions_root, index).unwrap();
+        verify_receipt_proof(receipt_proof, &receipts_root, index).unwrap();
+    }
+
+    #[test]
+    fn should_get_proof_bundle_with_key_of_first_tx_from_state() {
+        let result = get_proof_bundle_from_state(get_state_with_both_branches(0)).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert!(json["key"] == "80");
+    }
+
+    #[test]
+    fn should_err_getting_proof_bundle_if_no_transaction_branch_in_state() {
+        let expected_err = "✘ No transaction_branch in state!";
+        let state = get_valid_state_with_transactions_trie()
+            .and_then(|state| state.set_index_in_state(0))
+            .and_then(|state| state.set_block_in_state(get_expected_block()))
+            .unwrap();
+        match get_proof_bundle_from_state(state) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Getting proof bundle should have failed!"),
+        }
+    }
+
+    #[test]
+    fn should_err_getting_proof_bundle_from_invalid_tx_hash() {
+        let expected_err = "✘ Passed in transaction hash has no hex prefix!";
+        match get_proof_bundle_from_tx_hash(WORKING_ENDPOINT, &SAMPLE_TX_HASH[2..]) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Getting proof bundle should have failed!"),
+        }
+    }
+
+    #[test]
+    #[ignore] // ~100 txs & receipts to get ∴ too expensive! Run w/ cargo +nightly test --ignored
+    fn should_get_proof_bundle_from_tx_hash() {
+        let result = get_proof_bundle_from_tx_hash(WORKING_ENDPOINT, SAMPLE_TX_HASH).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert!(json["key"] == "60");
+    }
+}
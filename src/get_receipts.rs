@@ -1,17 +1,54 @@
+use crate::constants::{LEGACY_TX_TYPE, MAX_TX_TYPE};
+use crate::errors::AppError;
 use crate::get_log::{get_logs_bloom_from_logs, get_logs_from_receipt_json};
 use crate::get_rpc_call_jsons::get_transaction_receipt_json;
 use crate::make_rpc_call::{deserialize_to_receipt_rpc_response, get_response_text, make_rpc_call};
 use crate::state::State;
-use crate::types::{Receipt, ReceiptJson, Result};
+use crate::types::{Byte, Receipt, ReceiptJson, ReceiptOutcome, Result};
 use crate::utils::{
     convert_h256_to_prefixed_hex, convert_hex_to_address, convert_hex_to_h256, convert_hex_to_u256,
     convert_json_value_to_string,
 };
-use ethereum_types::{Address, H160, H256};
+use ethereum_types::{Address, H160, H256, U256};
+
+fn get_tx_type_from_receipt_json(tx_type: &Option<String>) -> Result<Byte> {
+    match tx_type {
+        None => Ok(LEGACY_TX_TYPE),
+        Some(hex) => match convert_hex_to_u256(hex.to_string())? {
+            tx_type if tx_type <= U256::from(MAX_TX_TYPE) => Ok(tx_type.low_u32() as Byte),
+            _ => Err(AppError::Custom(format!(
+                "✘ Unsupported transaction type in receipt: {}",
+                hex
+            ))),
+        },
+    }
+}
+
+fn get_receipt_outcome_from_receipt_json(
+    status: &Option<String>,
+    root: &Option<String>,
+) -> Result<ReceiptOutcome> {
+    match (status.as_deref(), root) {
+        (Some("0x1"), _) => Ok(ReceiptOutcome::Status(true)),
+        (Some("0x0"), _) => Ok(ReceiptOutcome::Status(false)),
+        (Some(status), _) => Err(AppError::Custom(format!(
+            "✘ Unrecognised status in receipt: {}",
+            status
+        ))),
+        (None, Some(root)) => Ok(ReceiptOutcome::PostStateRoot(convert_hex_to_h256(
+            root.to_string(),
+        )?)),
+        (None, None) => Err(AppError::Custom(
+            "✘ Receipt has neither a status nor a post-state root!".to_string(),
+        )),
+    }
+}
 
 pub fn deserialize_receipt_json_to_receipt_struct(receipt: ReceiptJson) -> Result<Receipt> {
     let logs = get_logs_from_receipt_json(&receipt)?;
     Ok(Receipt {
+        tx_type: get_tx_type_from_receipt_json(&receipt.r#type)?,
+        outcome: get_receipt_outcome_from_receipt_json(&receipt.status, &receipt.root)?,
         from: convert_hex_to_address(receipt.from)?,
         logs_bloom: get_logs_bloom_from_logs(&logs)?,
         gas_used: convert_hex_to_u256(receipt.gasUsed)?,
@@ -20,11 +57,6 @@ pub fn deserialize_receipt_json_to_receipt_struct(receipt: ReceiptJson) -> Resul
         transaction_hash: convert_hex_to_h256(receipt.transactionHash)?,
         transaction_index: convert_hex_to_u256(receipt.transactionIndex)?,
         cumulative_gas_used: convert_hex_to_u256(receipt.cumulativeGasUsed)?,
-        status: match receipt.status.as_ref() {
-            "0x1" => true,
-            "0x0" => false,
-            _ => false,
-        },
         to: match receipt.to {
             serde_json::Value::Null => H160::zero(),
             _ => convert_hex_to_address(convert_json_value_to_string(receipt.to)?)?,
@@ -45,7 +77,7 @@ pub fn get_receipt_from_tx_hash(endpoint: &str, tx_hash: &str) -> Result<Receipt
         .and_then(|res| deserialize_receipt_json_to_receipt_struct(res.result))
 }
 
-fn get_receipts_from_tx_hashes(endpoint: &str, tx_hashes: &[H256]) -> Result<Vec<Receipt>> {
+pub fn get_receipts_from_tx_hashes(endpoint: &str, tx_hashes: &[H256]) -> Result<Vec<Receipt>> {
     tx_hashes
         .iter()
         .map(|tx_hash| get_receipt_from_tx_hash(endpoint, &convert_h256_to_prefixed_hex(*tx_hash)?))
@@ -66,9 +98,9 @@ mod tests {
     use super::*;
     use crate::make_rpc_call::deserialize_to_receipt_rpc_response;
     use crate::test_utils::{
-        assert_receipt_is_correct, get_expected_block, get_valid_state_with_endpoint,
-        get_valid_tx_hash_h256, SAMPLE_RECEIPT_JSON_PATH, SAMPLE_TX_HASH, TX_INDEX,
-        WORKING_ENDPOINT,
+        assert_receipt_is_correct, get_expected_block, get_sample_client_receipt_json_path,
+        get_valid_state_with_endpoint, get_valid_tx_hash_h256, SAMPLE_CLIENT_NAMES,
+        SAMPLE_RECEIPT_JSON_PATH, SAMPLE_TX_HASH, TX_INDEX, WORKING_ENDPOINT,
     };
     use std::fs;
 
@@ -78,6 +110,17 @@ mod tests {
         assert_receipt_is_correct(result)
     }
 
+    #[test]
+    fn should_deserialize_receipt_json_from_each_client_correctly() {
+        SAMPLE_CLIENT_NAMES.iter().for_each(|client| {
+            let receipt_string =
+                fs::read_to_string(get_sample_client_receipt_json_path(client)).unwrap();
+            let receipt_json = deserialize_to_receipt_rpc_response(receipt_string).unwrap();
+            let result = deserialize_receipt_json_to_receipt_struct(receipt_json.result).unwrap();
+            assert_receipt_is_correct(result)
+        });
+    }
+
     #[test]
     fn should_deserialize_receipt_json_to_receipt_struct_correctly() {
         let receipt_string = fs::read_to_string(SAMPLE_RECEIPT_JSON_PATH).unwrap();
@@ -86,6 +129,67 @@ mod tests {
         assert_receipt_is_correct(result)
     }
 
+    #[test]
+    fn should_get_legacy_tx_type_if_receipt_json_has_no_type() {
+        let result = get_tx_type_from_receipt_json(&None).unwrap();
+        assert!(result == LEGACY_TX_TYPE);
+    }
+
+    #[test]
+    fn should_get_tx_types_from_receipt_json() {
+        ["0x0", "0x1", "0x2", "0x3", "0x4"]
+            .iter()
+            .enumerate()
+            .for_each(|(i, hex)| {
+                let result = get_tx_type_from_receipt_json(&Some(hex.to_string())).unwrap();
+                assert!(result as usize == i);
+            });
+    }
+
+    #[test]
+    fn should_err_if_receipt_json_tx_type_is_out_of_range() {
+        let expected_err = "✘ Unsupported transaction type in receipt: 0x80";
+        match get_tx_type_from_receipt_json(&Some("0x80".to_string())) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Tx type should be out of range!"),
+        }
+    }
+
+    #[test]
+    fn should_get_status_outcomes_from_receipt_json() {
+        let success = get_receipt_outcome_from_receipt_json(&Some("0x1".to_string()), &None);
+        let failure = get_receipt_outcome_from_receipt_json(&Some("0x0".to_string()), &None);
+        assert!(success.unwrap() == ReceiptOutcome::Status(true));
+        assert!(failure.unwrap() == ReceiptOutcome::Status(false));
+    }
+
+    #[test]
+    fn should_get_post_state_root_outcome_from_pre_byzantium_receipt_json() {
+        let root_hex = SAMPLE_TX_HASH.to_string();
+        let expected_result =
+            ReceiptOutcome::PostStateRoot(convert_hex_to_h256(root_hex.clone()).unwrap());
+        let result = get_receipt_outcome_from_receipt_json(&None, &Some(root_hex)).unwrap();
+        assert!(result == expected_result);
+    }
+
+    #[test]
+    fn should_err_if_receipt_json_status_is_unrecognised() {
+        let expected_err = "✘ Unrecognised status in receipt: 0x2";
+        match get_receipt_outcome_from_receipt_json(&Some("0x2".to_string()), &None) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Status should be unrecognised!"),
+        }
+    }
+
+    #[test]
+    fn should_err_if_receipt_json_has_no_status_or_root() {
+        let expected_err = "✘ Receipt has neither a status nor a post-state root!";
+        match get_receipt_outcome_from_receipt_json(&None, &None) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Receipt outcome should be missing!"),
+        }
+    }
+
     #[test]
     fn should_get_receipts_from_tx_hashes_correctly() {
         let tx_hash_h256 = get_valid_tx_hash_h256().unwrap();
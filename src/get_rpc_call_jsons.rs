@@ -28,6 +28,24 @@ pub fn get_transaction_receipt_json(tx_hash: &str) -> Result<Value> {
     }))
 }
 
+pub fn get_transaction_by_hash_json(tx_hash: &str) -> Result<Value> {
+    Ok(json!({
+        "id": "1",
+        "jsonrpc": "2.0",
+        "method": "eth_getTransactionByHash",
+        "params": [ tx_hash ],
+    }))
+}
+
+pub fn get_proof_json(address: &str, storage_keys: &[String], block: &str) -> Result<Value> {
+    Ok(json!({
+        "id": "1",
+        "jsonrpc": "2.0",
+        "method": "eth_getProof",
+        "params": [ address, storage_keys, block ],
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -66,4 +84,26 @@ mod tests {
         assert!("\"eth_getTransactionReceipt\"" == result["method"].to_string());
         assert!(expected_result == result["params"][0].to_string());
     }
+    #[test]
+    fn should_get_transaction_by_hash_json_correctly() {
+        let dummy_hash = "0xc0ffee".to_string();
+        let expected_result = format!("\"{}\"", &dummy_hash);
+        let result = get_transaction_by_hash_json(&dummy_hash).unwrap();
+        assert!("\"1\"" == result["id"].to_string());
+        assert!("\"2.0\"" == result["jsonrpc"].to_string());
+        assert!("\"eth_getTransactionByHash\"" == result["method"].to_string());
+        assert!(expected_result == result["params"][0].to_string());
+    }
+    #[test]
+    fn should_get_proof_json_correctly() {
+        let dummy_address = "0xc0ffee".to_string();
+        let storage_keys = vec!["0x0".to_string(), "0x1".to_string()];
+        let result = get_proof_json(&dummy_address, &storage_keys, "latest").unwrap();
+        assert!("\"1\"" == result["id"].to_string());
+        assert!("\"2.0\"" == result["jsonrpc"].to_string());
+        assert!("\"eth_getProof\"" == result["method"].to_string());
+        assert!("\"0xc0ffee\"" == result["params"][0].to_string());
+        assert!("[\"0x0\",\"0x1\"]" == result["params"][1].to_string());
+        assert!("\"latest\"" == result["params"][2].to_string());
+    }
 }
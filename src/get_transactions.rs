@@ -0,0 +1,299 @@
+use crate::constants::{
+    ACCESS_LIST_TX_TYPE, BLOB_TX_TYPE, DYNAMIC_FEE_TX_TYPE, LEGACY_TX_TYPE, MAX_TX_TYPE,
+    SET_CODE_TX_TYPE,
+};
+use crate::errors::AppError;
+use crate::get_rpc_call_jsons::get_transaction_by_hash_json;
+use crate::make_rpc_call::{
+    deserialize_to_transaction_rpc_response, get_response_text, make_rpc_call,
+};
+use crate::state::State;
+use crate::types::{
+    AccessListItem, AccessListItemJson, Authorization, AuthorizationJson, Byte, Result,
+    Transaction, TransactionJson,
+};
+use crate::utils::{
+    convert_h256_to_prefixed_hex, convert_hex_strings_to_h256s, convert_hex_to_address,
+    convert_hex_to_bytes, convert_hex_to_h256, convert_hex_to_u256,
+};
+use ethereum_types::{H256, U256};
+
+fn get_tx_type_from_transaction_json(tx_type: &Option<String>) -> Result<Byte> {
+    match tx_type {
+        None => Ok(LEGACY_TX_TYPE),
+        Some(hex) => match convert_hex_to_u256(hex.to_string())? {
+            tx_type if tx_type <= U256::from(MAX_TX_TYPE) => Ok(tx_type.low_u32() as Byte),
+            _ => Err(AppError::Custom(format!(
+                "✘ Unsupported transaction type: {}",
+                hex
+            ))),
+        },
+    }
+}
+
+fn get_required_tx_field<T>(tx_type: Byte, field_name: &str, field: Option<T>) -> Result<T> {
+    match field {
+        Some(field) => Ok(field),
+        None => Err(AppError::Custom(format!(
+            "✘ Type {} transaction is missing its {} field!",
+            tx_type, field_name
+        ))),
+    }
+}
+
+fn get_optional_u256_from_hex(hex: Option<String>) -> Result<Option<U256>> {
+    hex.map(convert_hex_to_u256).transpose()
+}
+
+fn deserialize_access_list_json(
+    access_list: Vec<AccessListItemJson>,
+) -> Result<Vec<AccessListItem>> {
+    access_list
+        .into_iter()
+        .map(|item| {
+            Ok(AccessListItem {
+                address: convert_hex_to_address(item.address)?,
+                storage_keys: convert_hex_strings_to_h256s(item.storageKeys)?,
+            })
+        })
+        .collect::<Result<Vec<AccessListItem>>>()
+}
+
+fn deserialize_authorization_list_json(
+    authorization_list: Vec<AuthorizationJson>,
+) -> Result<Vec<Authorization>> {
+    authorization_list
+        .into_iter()
+        .map(|authorization| {
+            Ok(Authorization {
+                chain_id: convert_hex_to_u256(authorization.chainId)?,
+                address: convert_hex_to_address(authorization.address)?,
+                nonce: convert_hex_to_u256(authorization.nonce)?,
+                y_parity: convert_hex_to_u256(authorization.yParity)?,
+                r: convert_hex_to_u256(authorization.r)?,
+                s: convert_hex_to_u256(authorization.s)?,
+            })
+        })
+        .collect::<Result<Vec<Authorization>>>()
+}
+
+fn check_transaction_has_fields_required_by_its_type(tx: &Transaction) -> Result<()> {
+    let tx_type = tx.tx_type;
+    match tx_type {
+        LEGACY_TX_TYPE | ACCESS_LIST_TX_TYPE => {
+            get_required_tx_field(tx_type, "gasPrice", tx.gas_price)?;
+        }
+        DYNAMIC_FEE_TX_TYPE | BLOB_TX_TYPE | SET_CODE_TX_TYPE => {
+            get_required_tx_field(tx_type, "maxPriorityFeePerGas", tx.max_priority_fee_per_gas)?;
+            get_required_tx_field(tx_type, "maxFeePerGas", tx.max_fee_per_gas)?;
+        }
+        _ => {
+            return Err(AppError::Custom(format!(
+                "✘ Unsupported transaction type: {}",
+                tx_type
+            )))
+        }
+    };
+    if tx_type != LEGACY_TX_TYPE {
+        get_required_tx_field(tx_type, "chainId", tx.chain_id)?;
+    };
+    match tx_type {
+        BLOB_TX_TYPE => {
+            get_required_tx_field(tx_type, "to", tx.to)?;
+            get_required_tx_field(tx_type, "maxFeePerBlobGas", tx.max_fee_per_blob_gas)?;
+        }
+        SET_CODE_TX_TYPE => {
+            get_required_tx_field(tx_type, "to", tx.to)?;
+        }
+        _ => (),
+    };
+    Ok(())
+}
+
+pub fn deserialize_transaction_json_to_transaction_struct(
+    tx: TransactionJson,
+) -> Result<Transaction> {
+    let tx_type = get_tx_type_from_transaction_json(&tx.r#type)?;
+    let transaction = Transaction {
+        tx_type,
+        hash: convert_hex_to_h256(tx.hash)?,
+        transaction_index: convert_hex_to_u256(tx.transactionIndex)?,
+        chain_id: get_optional_u256_from_hex(tx.chainId)?,
+        nonce: convert_hex_to_u256(tx.nonce)?,
+        gas_price: get_optional_u256_from_hex(tx.gasPrice)?,
+        max_priority_fee_per_gas: get_optional_u256_from_hex(tx.maxPriorityFeePerGas)?,
+        max_fee_per_gas: get_optional_u256_from_hex(tx.maxFeePerGas)?,
+        gas: convert_hex_to_u256(tx.gas)?,
+        to: tx.to.map(convert_hex_to_address).transpose()?,
+        value: convert_hex_to_u256(tx.value)?,
+        input: convert_hex_to_bytes(tx.input)?,
+        access_list: deserialize_access_list_json(tx.accessList.unwrap_or_default())?,
+        max_fee_per_blob_gas: get_optional_u256_from_hex(tx.maxFeePerBlobGas)?,
+        blob_versioned_hashes: convert_hex_strings_to_h256s(
+            tx.blobVersionedHashes.unwrap_or_default(),
+        )?,
+        authorization_list: deserialize_authorization_list_json(
+            tx.authorizationList.unwrap_or_default(),
+        )?,
+        v: match (tx_type, tx.yParity) {
+            (LEGACY_TX_TYPE, _) | (_, None) => convert_hex_to_u256(tx.v)?,
+            (_, Some(y_parity)) => convert_hex_to_u256(y_parity)?,
+        },
+        r: convert_hex_to_u256(tx.r)?,
+        s: convert_hex_to_u256(tx.s)?,
+    };
+    check_transaction_has_fields_required_by_its_type(&transaction).map(|_| transaction)
+}
+
+pub fn get_transaction_from_tx_hash(endpoint: &str, tx_hash: &str) -> Result<Transaction> {
+    get_transaction_by_hash_json(tx_hash)
+        .and_then(|rpc_json| make_rpc_call(endpoint, rpc_json))
+        .and_then(get_response_text)
+        .and_then(deserialize_to_transaction_rpc_response)
+        .and_then(|res| deserialize_transaction_json_to_transaction_struct(res.result))
+}
+
+pub fn get_transactions_from_tx_hashes(
+    endpoint: &str,
+    tx_hashes: &[H256],
+) -> Result<Vec<Transaction>> {
+    tx_hashes
+        .iter()
+        .map(|tx_hash| {
+            get_transaction_from_tx_hash(endpoint, &convert_h256_to_prefixed_hex(*tx_hash)?)
+        })
+        .collect::<Result<Vec<Transaction>>>()
+}
+
+pub fn get_all_transactions_from_block_in_state_and_set_in_state(state: State) -> Result<State> {
+    info!("✔ Getting all transactions from block...");
+    get_transactions_from_tx_hashes(
+        State::get_endpoint_from_state(&state)?,
+        &State::get_block_from_state(&state)?.transactions,
+    )
+    .and_then(|transactions| State::set_transactions_in_state(state, transactions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::make_rpc_call::deserialize_to_transaction_rpc_response;
+    use crate::test_utils::{
+        get_sample_transaction_hashes, get_sample_transaction_json_path, get_sample_transactions,
+    };
+    use std::fs;
+
+    fn get_sample_transaction_json(tx_type: Byte) -> TransactionJson {
+        let tx_hash = &get_sample_transaction_hashes()[tx_type as usize];
+        fs::read_to_string(get_sample_transaction_json_path(tx_hash))
+            .map(|string| deserialize_to_transaction_rpc_response(string).unwrap())
+            .unwrap()
+            .result
+    }
+
+    #[test]
+    fn should_deserialize_transaction_json_of_each_type_correctly() {
+        let tx_hashes = get_sample_transaction_hashes();
+        get_sample_transactions()
+            .iter()
+            .enumerate()
+            .for_each(|(i, tx)| {
+                assert!(tx.tx_type as usize == i);
+                assert!(tx.transaction_index == U256::from(i));
+                assert!(convert_h256_to_prefixed_hex(tx.hash).unwrap() == tx_hashes[i]);
+            });
+    }
+
+    #[test]
+    fn should_deserialize_legacy_transaction_json_correctly() {
+        let tx = deserialize_transaction_json_to_transaction_struct(get_sample_transaction_json(
+            LEGACY_TX_TYPE,
+        ))
+        .unwrap();
+        assert!(tx.nonce == U256::from(9));
+        assert!(tx.v == U256::from(37));
+        assert!(tx.gas == U256::from(21_000));
+        assert!(tx.gas_price == Some(U256::from(20_000_000_000u64)));
+        assert!(tx.value == U256::from(1_000_000_000_000_000_000u64));
+        assert!(tx.input.is_empty());
+    }
+
+    #[test]
+    fn should_deserialize_typed_transaction_json_fields_correctly() {
+        let access_list_tx = deserialize_transaction_json_to_transaction_struct(
+            get_sample_transaction_json(ACCESS_LIST_TX_TYPE),
+        )
+        .unwrap();
+        let dynamic_fee_tx = deserialize_transaction_json_to_transaction_struct(
+            get_sample_transaction_json(DYNAMIC_FEE_TX_TYPE),
+        )
+        .unwrap();
+        let blob_tx = deserialize_transaction_json_to_transaction_struct(
+            get_sample_transaction_json(BLOB_TX_TYPE),
+        )
+        .unwrap();
+        let set_code_tx = deserialize_transaction_json_to_transaction_struct(
+            get_sample_transaction_json(SET_CODE_TX_TYPE),
+        )
+        .unwrap();
+        assert!(access_list_tx.access_list.len() == 1);
+        assert!(access_list_tx.access_list[0].storage_keys.len() == 2);
+        assert!(access_list_tx.input.len() == 68);
+        assert!(dynamic_fee_tx.to.is_none());
+        assert!(dynamic_fee_tx.max_fee_per_gas == Some(U256::from(80_000_000_000u64)));
+        assert!(blob_tx.blob_versioned_hashes.len() == 2);
+        assert!(blob_tx.max_fee_per_blob_gas == Some(U256::from(1_000_000_000)));
+        assert!(set_code_tx.authorization_list.len() == 1);
+        assert!(set_code_tx.authorization_list[0].nonce == U256::from(4));
+    }
+
+    #[test]
+    fn should_get_typed_transaction_v_from_y_parity() {
+        let mut tx_json = get_sample_transaction_json(ACCESS_LIST_TX_TYPE);
+        tx_json.v = "0x25".to_string();
+        let tx = deserialize_transaction_json_to_transaction_struct(tx_json).unwrap();
+        assert!(tx.v == U256::one());
+    }
+
+    #[test]
+    fn should_err_if_transaction_json_tx_type_is_unsupported() {
+        let expected_err = "✘ Unsupported transaction type: 5";
+        let mut tx_json = get_sample_transaction_json(SET_CODE_TX_TYPE);
+        tx_json.r#type = Some("0x5".to_string());
+        match deserialize_transaction_json_to_transaction_struct(tx_json) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Tx type should be unsupported!"),
+        }
+    }
+
+    #[test]
+    fn should_err_if_transaction_json_tx_type_is_out_of_range() {
+        let expected_err = "✘ Unsupported transaction type: 0x80";
+        match get_tx_type_from_transaction_json(&Some("0x80".to_string())) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Tx type should be out of range!"),
+        }
+    }
+
+    #[test]
+    fn should_err_if_transaction_json_is_missing_a_field_required_by_its_type() {
+        let expected_err = "✘ Type 2 transaction is missing its maxFeePerGas field!";
+        let mut tx_json = get_sample_transaction_json(DYNAMIC_FEE_TX_TYPE);
+        tx_json.maxFeePerGas = None;
+        match deserialize_transaction_json_to_transaction_struct(tx_json) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Transaction should be missing a field!"),
+        }
+    }
+
+    #[test]
+    fn should_err_if_blob_transaction_json_has_no_recipient() {
+        let expected_err = "✘ Type 3 transaction is missing its to field!";
+        let mut tx_json = get_sample_transaction_json(BLOB_TX_TYPE);
+        tx_json.to = None;
+        match deserialize_transaction_json_to_transaction_struct(tx_json) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Blob transaction should be missing its recipient!"),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::rlp_codec::get_rlp_encoded_transactions_and_nibble_tuples;
+use crate::state::State;
+use crate::trie::{put_in_trie_recursively, Trie};
+use crate::types::{Result, Transaction};
+
+pub fn get_transactions_trie_from_transactions(transactions: &[Transaction]) -> Result<Trie> {
+    get_rlp_encoded_transactions_and_nibble_tuples(transactions).and_then(|key_value_tuples| {
+        put_in_trie_recursively(Trie::get_new_trie()?, key_value_tuples, 0)
+    })
+}
+
+pub fn get_transactions_trie_and_set_in_state(state: State) -> Result<State> {
+    info!("✔ Building merkle-patricia trie from transactions...");
+    get_transactions_trie_from_transactions(state.get_transactions_from_state()?)
+        .and_then(|trie| state.set_transactions_trie_in_state(trie))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{get_sample_transactions, get_valid_initial_state, TRANSACTIONS_ROOT};
+    use crate::utils::convert_h256_to_prefixed_hex;
+
+    #[test]
+    fn should_get_transactions_trie_from_transactions() {
+        let transactions = get_sample_transactions();
+        let result = get_transactions_trie_from_transactions(&transactions).unwrap();
+        let root_hex = convert_h256_to_prefixed_hex(result.root).unwrap();
+        assert!(root_hex == TRANSACTIONS_ROOT);
+    }
+
+    #[test]
+    fn should_get_transactions_trie_from_state() {
+        let state = get_valid_initial_state().unwrap();
+        let transactions = get_sample_transactions();
+        let state_with_transactions = state.set_transactions_in_state(transactions).unwrap();
+        let result = get_transactions_trie_and_set_in_state(state_with_transactions).unwrap();
+        let trie_from_state = result.get_transactions_trie_from_state().unwrap();
+        let root_hex = convert_h256_to_prefixed_hex(trie_from_state.root).unwrap();
+        assert!(root_hex == TRANSACTIONS_ROOT);
+    }
+}
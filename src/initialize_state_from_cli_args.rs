@@ -1,7 +1,5 @@
 use crate::parse_cli_args::CliArgs;
-use crate::state::State;
-use crate::types::Result;
-use crate::utils::convert_hex_to_h256;
+use rusty_receipt_proof_maker::{convert_hex_to_h256, Result, State};
 
 pub fn initialize_state_from_cli_args(cli_args: CliArgs) -> Result<State> {
     info!("✔ Initializing state from CLI args...");
@@ -14,15 +12,26 @@ pub fn initialize_state_from_cli_args(cli_args: CliArgs) -> Result<State> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::get_valid_tx_hash_hex;
 
     #[test]
     fn should_create_state_from_cli_args() {
         let expected_verbosity = true;
-        let tx_hash = get_valid_tx_hash_hex();
+        let tx_hash =
+            "0xd6f577a93332e015438fcca4e73f538b1829acbd7eb0cf9ee5a0a73ff2752cc6".to_string();
         let expected_tx_hash = convert_hex_to_h256(tx_hash.clone()).unwrap();
         let cli_args = CliArgs {
+            cmd_tx: false,
+            cmd_bundle: false,
+            cmd_verify: false,
+            cmd_account: false,
+            flag_header: false,
             arg_txhash: tx_hash,
+            arg_index: String::new(),
+            arg_proof: String::new(),
+            arg_receiptsroot: String::new(),
+            arg_address: String::new(),
+            arg_storagekey: Vec::new(),
+            flag_block: String::new(),
             flag_verbose: expected_verbosity,
         };
         let state = initialize_state_from_cli_args(cli_args).unwrap();
@@ -0,0 +1,123 @@
+mod check_block_hash;
+mod check_receipts_root;
+mod check_transactions_root;
+mod connect_to_node;
+mod constants;
+mod errors;
+mod get_account_proof;
+mod get_block;
+mod get_branch_from_trie;
+mod get_database;
+mod get_endpoint;
+mod get_hex_proof_from_branch;
+mod get_keccak_hash;
+mod get_log;
+mod get_proof_bundle;
+mod get_receipts;
+mod get_receipts_trie;
+mod get_rpc_call_jsons;
+mod get_transactions;
+mod get_transactions_trie;
+mod get_tx_index;
+mod make_rpc_call;
+mod nibble_utils;
+mod node_store;
+mod path_codec;
+mod proof_recorder;
+mod rlp_codec;
+mod state;
+mod test_fixtures;
+mod test_utils;
+mod trie;
+mod trie_iterator;
+mod trie_nodes;
+mod types;
+mod utils;
+mod validate_tx_hash;
+mod verify_proof;
+mod verify_state_proof;
+
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+#[cfg(test)]
+#[macro_use]
+extern crate serial_test_derive;
+
+pub use crate::errors::AppError;
+pub use crate::state::State;
+pub use crate::types::{
+    AccessListItem, Account, AccountProof, Authorization, Block, BlockHeader, Bytes, Database,
+    HexProof, Log, NodeStack, Receipt, Result, StorageProof, Transaction,
+};
+
+// Fetching blocks, receipts & transactions from a node:
+pub use crate::get_block::{get_block_by_blockhash, get_block_by_number};
+pub use crate::get_receipts::{get_receipt_from_tx_hash, get_receipts_from_tx_hashes};
+pub use crate::get_transactions::{get_transaction_from_tx_hash, get_transactions_from_tx_hashes};
+
+// Fetching, loading & verifying account & storage proofs (EIP-1186):
+pub use crate::get_account_proof::{get_account_proof, get_account_proof_from_rpc_response};
+pub use crate::rlp_codec::{rlp_decode_account, rlp_decode_storage_value};
+pub use crate::verify_state_proof::{
+    verify_account_and_storage_proofs, verify_account_proof, verify_storage_proof,
+};
+
+// Building the receipts & transactions tries & pulling branches from them:
+pub use crate::get_branch_from_trie::{
+    convert_usize_index_to_trie_key, get_branch_from_trie, get_branches_from_trie,
+    get_exclusion_branch_from_trie, get_range_branch_from_trie, get_root_from_key_value_tuples,
+    get_transaction_branch_from_trie, get_transaction_count_branches_from_trie,
+    get_transaction_exclusion_branch_from_trie,
+};
+pub use crate::get_receipts_trie::get_receipts_trie_from_receipts;
+pub use crate::get_transactions_trie::get_transactions_trie_from_transactions;
+pub use crate::nibble_utils::{get_nibbles_from_bytes, Nibbles};
+pub use crate::node_store::{FileNodeStore, MemoryNodeStore, NodeStore};
+pub use crate::proof_recorder::{get_recording_trie, ProofRecorder};
+pub use crate::rlp_codec::{
+    get_rlp_encoded_receipts_and_nibble_tuples, get_rlp_encoded_transactions_and_nibble_tuples,
+};
+pub use crate::trie::{get_secure_trie_key, put_in_trie_recursively, Trie};
+pub use crate::trie_iterator::{get_trie_iterator, TrieIterator};
+pub use crate::trie_nodes::{rlp_decode_node, Node};
+
+// Encoding & verifying proofs:
+pub use crate::get_hex_proof_from_branch::{get_hex_proof_from_branch, rlp_encode_node_stack};
+pub use crate::get_proof_bundle::{get_proof_bundle_from_state, get_proof_bundle_from_tx_hash};
+pub use crate::rlp_codec::rlp_encode_block_header;
+pub use crate::verify_proof::{
+    decode_hex_proof_to_rlp_encoded_nodes, verify_exclusion_proof_nodes, verify_multiproof_nodes,
+    verify_proof_nodes, verify_range_proof_nodes, verify_receipt_exclusion_proof,
+    verify_receipt_multiproof, verify_receipt_proof, verify_receipt_range_proof,
+    verify_transaction_count_proof, verify_transaction_exclusion_proof, verify_transaction_proof,
+};
+
+// The proof-making pipeline, stage by stage:
+pub use crate::check_block_hash::check_block_hash_in_state;
+pub use crate::check_receipts_root::check_receipts_root_in_state;
+pub use crate::check_transactions_root::check_transactions_root_in_state;
+pub use crate::connect_to_node::connect_to_node;
+pub use crate::get_block::get_block_from_tx_hash_in_state_and_set_in_state;
+pub use crate::get_branch_from_trie::{
+    get_branch_from_trie_and_put_in_state, get_transaction_branch_from_trie_and_put_in_state,
+};
+pub use crate::get_endpoint::{get_endpoint_and_set_in_state, get_endpoint_from_env_vars};
+pub use crate::get_hex_proof_from_branch::{
+    get_hex_proof_from_branch_in_state, get_hex_proof_from_transaction_branch_in_state,
+    get_hex_proof_with_header_from_state, get_hex_transaction_proof_with_header_from_state,
+};
+pub use crate::get_proof_bundle::get_proof_bundle_for_tx_hash_in_state;
+pub use crate::get_receipts::get_all_receipts_from_block_in_state_and_set_in_state;
+pub use crate::get_receipts_trie::get_receipts_trie_and_set_in_state;
+pub use crate::get_transactions::get_all_transactions_from_block_in_state_and_set_in_state;
+pub use crate::get_transactions_trie::get_transactions_trie_and_set_in_state;
+pub use crate::get_tx_index::get_tx_index_and_add_to_state;
+
+// Helpers:
+pub use crate::utils::{
+    convert_bytes_to_hex, convert_h256_to_prefixed_hex, convert_hex_to_h256,
+    convert_num_string_to_usize,
+};
+pub use crate::validate_tx_hash::validate_tx_hash;
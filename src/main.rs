@@ -1,76 +1,91 @@
-mod connect_to_node;
-mod constants;
-mod errors;
-mod get_block;
-mod get_branch_from_trie;
-mod get_database;
-mod get_endpoint;
-mod get_hex_proof_from_branch;
-mod get_keccak_hash;
-mod get_log;
-mod get_receipts;
-mod get_receipts_trie;
-mod get_rpc_call_jsons;
-mod get_tx_index;
 mod initialize_state_from_cli_args;
-mod make_rpc_call;
-mod nibble_utils;
 mod parse_cli_args;
-mod path_codec;
-mod rlp_codec;
-mod state;
-mod test_utils;
-mod trie;
-mod trie_nodes;
-mod types;
+mod test_fixtures;
 mod usage_info;
-mod utils;
 mod validate_cli_args;
-mod validate_tx_hash;
+mod verify_account_proof_from_cli_args;
+mod verify_proof_from_cli_args;
 
 #[macro_use]
 extern crate log;
 #[macro_use]
-extern crate serde_json;
-#[macro_use]
 extern crate serde_derive;
-#[cfg(test)]
-#[macro_use]
-extern crate serial_test_derive;
 
-use crate::connect_to_node::connect_to_node;
-use crate::get_block::get_block_from_tx_hash_in_state_and_set_in_state;
-use crate::get_branch_from_trie::get_branch_from_trie_and_put_in_state;
-use crate::get_endpoint::get_endpoint_and_set_in_state;
-use crate::get_hex_proof_from_branch::get_hex_proof_from_branch_in_state;
-use crate::get_receipts::get_all_receipts_from_block_in_state_and_set_in_state;
-use crate::get_receipts_trie::get_receipts_trie_and_set_in_state;
-use crate::get_tx_index::get_tx_index_and_add_to_state;
 use crate::initialize_state_from_cli_args::initialize_state_from_cli_args;
 use crate::parse_cli_args::parse_cli_args;
 use crate::validate_cli_args::validate_cli_args;
+use crate::verify_account_proof_from_cli_args::verify_account_proof_from_cli_args;
+use crate::verify_proof_from_cli_args::verify_proof_from_cli_args;
+use rusty_receipt_proof_maker::{
+    check_block_hash_in_state, check_receipts_root_in_state, check_transactions_root_in_state,
+    connect_to_node, get_all_receipts_from_block_in_state_and_set_in_state,
+    get_all_transactions_from_block_in_state_and_set_in_state,
+    get_block_from_tx_hash_in_state_and_set_in_state, get_branch_from_trie_and_put_in_state,
+    get_endpoint_and_set_in_state, get_hex_proof_from_branch_in_state,
+    get_hex_proof_from_transaction_branch_in_state, get_hex_proof_with_header_from_state,
+    get_hex_transaction_proof_with_header_from_state, get_proof_bundle_for_tx_hash_in_state,
+    get_receipts_trie_and_set_in_state, get_transaction_branch_from_trie_and_put_in_state,
+    get_transactions_trie_and_set_in_state, get_tx_index_and_add_to_state,
+};
 
 fn main() {
-    println!("x");
     match parse_cli_args()
-        .and_then(|state| {
-            println!("poop");
-            validate_cli_args(state)
-        })
-        .and_then(initialize_state_from_cli_args)
-        .and_then(get_endpoint_and_set_in_state)
-        .and_then(connect_to_node)
-        .and_then(get_block_from_tx_hash_in_state_and_set_in_state)
-        .and_then(get_all_receipts_from_block_in_state_and_set_in_state)
-        .and_then(get_tx_index_and_add_to_state)
-        .and_then(get_receipts_trie_and_set_in_state)
-        .and_then(get_branch_from_trie_and_put_in_state)
-        .and_then(get_hex_proof_from_branch_in_state)
-    {
-        Ok(hex_proof) => {
-            info!("✔ Hex Proof:\n");
-            trace!("{}", hex_proof);
-            println!("{}", hex_proof);
+        .and_then(validate_cli_args)
+        .and_then(|cli_args| {
+            match (
+                cli_args.cmd_verify,
+                cli_args.cmd_account,
+                cli_args.cmd_tx,
+                cli_args.cmd_bundle,
+            ) {
+                (true, _, _, _) => verify_proof_from_cli_args(cli_args),
+                (false, true, _, _) => verify_account_proof_from_cli_args(cli_args),
+                (false, false, _, true) => initialize_state_from_cli_args(cli_args)
+                    .and_then(get_endpoint_and_set_in_state)
+                    .and_then(connect_to_node)
+                    .and_then(get_proof_bundle_for_tx_hash_in_state)
+                    .inspect(|_| info!("✔ Proof Bundle:\n")),
+                (false, false, true, false) => {
+                    let include_header = cli_args.flag_header;
+                    initialize_state_from_cli_args(cli_args)
+                        .and_then(get_endpoint_and_set_in_state)
+                        .and_then(connect_to_node)
+                        .and_then(get_block_from_tx_hash_in_state_and_set_in_state)
+                        .and_then(check_block_hash_in_state)
+                        .and_then(get_all_transactions_from_block_in_state_and_set_in_state)
+                        .and_then(get_tx_index_and_add_to_state)
+                        .and_then(get_transactions_trie_and_set_in_state)
+                        .and_then(check_transactions_root_in_state)
+                        .and_then(get_transaction_branch_from_trie_and_put_in_state)
+                        .and_then(|state| match include_header {
+                            true => get_hex_transaction_proof_with_header_from_state(state),
+                            false => get_hex_proof_from_transaction_branch_in_state(state),
+                        })
+                        .inspect(|_| info!("✔ Hex Proof:\n"))
+                }
+                (false, false, false, false) => {
+                    let include_header = cli_args.flag_header;
+                    initialize_state_from_cli_args(cli_args)
+                        .and_then(get_endpoint_and_set_in_state)
+                        .and_then(connect_to_node)
+                        .and_then(get_block_from_tx_hash_in_state_and_set_in_state)
+                        .and_then(check_block_hash_in_state)
+                        .and_then(get_all_receipts_from_block_in_state_and_set_in_state)
+                        .and_then(get_tx_index_and_add_to_state)
+                        .and_then(get_receipts_trie_and_set_in_state)
+                        .and_then(check_receipts_root_in_state)
+                        .and_then(get_branch_from_trie_and_put_in_state)
+                        .and_then(|state| match include_header {
+                            true => get_hex_proof_with_header_from_state(state),
+                            false => get_hex_proof_from_branch_in_state(state),
+                        })
+                        .inspect(|_| info!("✔ Hex Proof:\n"))
+                }
+            }
+        }) {
+        Ok(output) => {
+            trace!("{}", output);
+            println!("{}", output);
         }
         Err(e) => {
             error!("{}", e);
@@ -1,7 +1,9 @@
 use crate::{
     constants::REQWEST_TIMEOUT_TIME,
     errors::AppError,
-    types::{BlockRpcResponse, ReceiptRpcResponse, Result},
+    types::{
+        BlockRpcResponse, ProofRpcResponse, ReceiptRpcResponse, Result, TransactionRpcResponse,
+    },
 };
 use serde_json::Value as Json;
 use std::time::Duration;
@@ -37,6 +39,16 @@ pub fn deserialize_to_receipt_rpc_response(rpc_call_result: String) -> Result<Re
     Ok(serde_json::from_str(&rpc_call_result)?)
 }
 
+pub fn deserialize_to_transaction_rpc_response(
+    rpc_call_result: String,
+) -> Result<TransactionRpcResponse> {
+    Ok(serde_json::from_str(&rpc_call_result)?)
+}
+
+pub fn deserialize_to_proof_rpc_response(rpc_call_result: String) -> Result<ProofRpcResponse> {
+    Ok(serde_json::from_str(&rpc_call_result)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
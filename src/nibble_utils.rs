@@ -287,6 +287,38 @@ pub fn get_length_in_nibbles(nibbles: &Nibbles) -> usize {
     nibbles.data.len() * 2 - nibbles.offset
 }
 
+pub fn get_nibbles_from_nibble_bytes(nibble_bytes: Bytes) -> Nibbles {
+    match nibble_bytes.len() % 2 {
+        0 => get_nibbles_from_bytes(
+            nibble_bytes
+                .chunks(2)
+                .map(|pair| merge_nibbles_from_bytes(pair[1], shift_nibble_left(pair[0])))
+                .collect(),
+        ),
+        _ => get_nibbles_from_offset_bytes(
+            [
+                vec![nibble_bytes[0]],
+                get_nibbles_from_nibble_bytes(nibble_bytes[1..].to_vec()).data,
+            ]
+            .concat(),
+        ),
+    }
+}
+
+pub fn get_nibble_bytes_from_nibbles(nibbles: &Nibbles) -> Result<Bytes> {
+    (0..get_length_in_nibbles(nibbles))
+        .map(|i| get_nibble_at_index(nibbles, i))
+        .collect()
+}
+
+pub fn concatenate_nibbles(nibbles_a: &Nibbles, nibbles_b: &Nibbles) -> Result<Nibbles> {
+    (0..get_length_in_nibbles(nibbles_a))
+        .map(|i| get_nibble_at_index(nibbles_a, i))
+        .chain((0..get_length_in_nibbles(nibbles_b)).map(|i| get_nibble_at_index(nibbles_b, i)))
+        .collect::<Result<Bytes>>()
+        .map(get_nibbles_from_nibble_bytes)
+}
+
 pub fn split_at_first_nibble(nibbles: &Nibbles) -> Result<(Nibbles, Nibbles)> {
     match get_length_in_nibbles(nibbles) > 0 {
         false => Ok((EMPTY_NIBBLES, EMPTY_NIBBLES)),
@@ -1476,4 +1508,64 @@ mod tests {
         assert!(result_nibble == EMPTY_NIBBLES);
         assert!(result_nibbles == EMPTY_NIBBLES);
     }
+
+    #[test]
+    fn should_get_nibble_bytes_from_nibbles() {
+        let nibbles = get_nibbles_from_offset_bytes(vec![0x0d, 0xec, 0xaf]);
+        let expected_result = vec![0xd, 0xe, 0xc, 0xa, 0xf];
+        let result = get_nibble_bytes_from_nibbles(&nibbles).unwrap();
+        assert!(result == expected_result);
+    }
+
+    #[test]
+    fn should_get_nibbles_from_nibble_bytes_of_nibbles() {
+        vec![
+            get_nibbles_from_bytes(vec![0x12, 0x34]),
+            get_nibbles_from_offset_bytes(vec![0x01, 0x23]),
+            EMPTY_NIBBLES,
+        ]
+        .into_iter()
+        .for_each(|nibbles| {
+            let nibble_bytes = get_nibble_bytes_from_nibbles(&nibbles).unwrap();
+            assert!(get_nibbles_from_nibble_bytes(nibble_bytes) == nibbles);
+        });
+    }
+
+    #[test]
+    fn should_concatenate_even_length_nibbles() {
+        let nibbles_a = get_nibbles_from_bytes(vec![0x12, 0x34]);
+        let nibbles_b = get_nibbles_from_bytes(vec![0x56]);
+        let expected_result = get_nibbles_from_bytes(vec![0x12, 0x34, 0x56]);
+        let result = concatenate_nibbles(&nibbles_a, &nibbles_b).unwrap();
+        assert!(result == expected_result);
+    }
+
+    #[test]
+    fn should_concatenate_odd_length_nibbles() {
+        let nibbles_a = get_nibbles_from_offset_bytes(vec![0x01, 0x23]);
+        let nibbles_b = get_nibbles_from_bytes(vec![0x45, 0x67]);
+        let expected_result = get_nibbles_from_offset_bytes(vec![0x01, 0x23, 0x45, 0x67]);
+        let result = concatenate_nibbles(&nibbles_a, &nibbles_b).unwrap();
+        assert!(result == expected_result);
+    }
+
+    #[test]
+    fn should_concatenate_two_odd_length_nibbles_to_even_length() {
+        let nibbles_a = get_nibbles_from_offset_bytes(vec![0x0a]);
+        let nibbles_b = get_nibbles_from_offset_bytes(vec![0x0b, 0xcd]);
+        let expected_result = get_nibbles_from_bytes(vec![0xab, 0xcd]);
+        let result = concatenate_nibbles(&nibbles_a, &nibbles_b).unwrap();
+        assert!(result == expected_result);
+    }
+
+    #[test]
+    fn should_concatenate_empty_nibbles() {
+        let nibbles = get_sample_offset_nibbles();
+        let result_1 = concatenate_nibbles(&EMPTY_NIBBLES, &nibbles).unwrap();
+        let result_2 = concatenate_nibbles(&nibbles, &EMPTY_NIBBLES).unwrap();
+        let result_3 = concatenate_nibbles(&EMPTY_NIBBLES, &EMPTY_NIBBLES).unwrap();
+        assert!(result_1 == nibbles);
+        assert!(result_2 == nibbles);
+        assert!(result_3 == EMPTY_NIBBLES);
+    }
 }
@@ -0,0 +1,257 @@
+use crate::errors::AppError;
+use crate::types::{Bytes, Database, Result};
+use ethereum_types::H256;
+use std::{
+    collections::HashMap,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+const REFERENCE_COUNT_LENGTH: usize = 8;
+
+/**
+ *
+ * Node Stores:
+ *
+ * A trie keeps its hashed nodes' rlp encodings in a store keyed by their
+ * hash, and walks the trie by fetching them back out again. Anything able to
+ * get, put, remove & check for such an encoding can back a trie, whether it
+ * lives in memory for the length of a single proof, or on disk so a trie can
+ * be reopened at its root later without being rebuilt.
+ *
+ * Identical nodes share a hash, and so may be referred to from several places
+ * at once, whether within one trie or across tries sharing a store. A store
+ * therefore counts references: each `put` adds one & each `remove` drops one,
+ * with a node only truly removed once nothing refers to it any more. Stale
+ * nodes can then be removed freely without pulling a node out from under
+ * anything else still using it.
+ *
+ */
+pub trait NodeStore {
+    fn get(&self, key: &H256) -> Result<Option<Bytes>>;
+    fn put(&mut self, key: H256, value: Bytes) -> Result<()>;
+    fn remove(&mut self, key: &H256) -> Result<()>;
+    fn contains(&self, key: &H256) -> Result<bool>;
+    fn get_reference_count(&self, key: &H256) -> Result<usize>;
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemoryNodeStore {
+    pub nodes: Database,
+    pub reference_counts: HashMap<H256, usize>,
+}
+
+impl NodeStore for MemoryNodeStore {
+    fn get(&self, key: &H256) -> Result<Option<Bytes>> {
+        Ok(self.nodes.get(key).map(|thing| thing.to_vec()))
+    }
+
+    fn put(&mut self, key: H256, value: Bytes) -> Result<()> {
+        *self.reference_counts.entry(key).or_insert(0) += 1;
+        self.nodes.insert(key, value);
+        Ok(())
+    }
+
+    fn remove(&mut self, key: &H256) -> Result<()> {
+        match self.reference_counts.get(key) {
+            None => Ok(()),
+            Some(1) => {
+                self.reference_counts.remove(key);
+                self.nodes.remove(key);
+                Ok(())
+            }
+            Some(count) => {
+                self.reference_counts.insert(*key, count - 1);
+                Ok(())
+            }
+        }
+    }
+
+    fn contains(&self, key: &H256) -> Result<bool> {
+        Ok(self.nodes.contains_key(key))
+    }
+
+    fn get_reference_count(&self, key: &H256) -> Result<usize> {
+        Ok(*self.reference_counts.get(key).unwrap_or(&0))
+    }
+}
+/**
+ *
+ * The File Node Store:
+ *
+ * Keeps each node in its own file, named for the node's hex hash, under the
+ * given directory, which is created if need be. Each file holds the node's
+ * reference count as eight big-endian bytes, followed by the node's rlp
+ * encoding. Nodes are only ever written under their own hash, so tries
+ * sharing a directory share any identical nodes too, with the count keeping
+ * each one alive for as long as any of them needs it.
+ *
+ */
+#[derive(Clone, Debug)]
+pub struct FileNodeStore {
+    pub directory: PathBuf,
+}
+
+impl FileNodeStore {
+    pub fn new(directory: &Path) -> Result<FileNodeStore> {
+        info!("✔ Opening file node store in {:?}...", directory);
+        fs::create_dir_all(directory)?;
+        Ok(FileNodeStore {
+            directory: directory.to_path_buf(),
+        })
+    }
+
+    fn get_path_from_key(&self, key: &H256) -> PathBuf {
+        self.directory.join(hex::encode(key))
+    }
+
+    fn read_counted_thing(&self, key: &H256) -> Result<Option<(usize, Bytes)>> {
+        match fs::read(self.get_path_from_key(key)) {
+            Ok(mut file) if file.len() >= REFERENCE_COUNT_LENGTH => {
+                let thing = file.split_off(REFERENCE_COUNT_LENGTH);
+                let mut count = [0u8; REFERENCE_COUNT_LENGTH];
+                count.copy_from_slice(&file);
+                Ok(Some((u64::from_be_bytes(count) as usize, thing)))
+            }
+            Ok(_) => Err(AppError::Custom(format!(
+                "✘ Node store file for {:?} is corrupt!",
+                key
+            ))),
+            Err(ref e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn write_counted_thing(&self, key: &H256, count: usize, thing: Bytes) -> Result<()> {
+        let mut file = (count as u64).to_be_bytes().to_vec();
+        file.extend(thing);
+        fs::write(self.get_path_from_key(key), file).map_err(|e| e.into())
+    }
+}
+
+impl NodeStore for FileNodeStore {
+    fn get(&self, key: &H256) -> Result<Option<Bytes>> {
+        self.read_counted_thing(key)
+            .map(|counted_thing| counted_thing.map(|(_, thing)| thing))
+    }
+
+    fn put(&mut self, key: H256, value: Bytes) -> Result<()> {
+        match self.read_counted_thing(&key)? {
+            Some((count, thing)) => self.write_counted_thing(&key, count + 1, thing),
+            None => self.write_counted_thing(&key, 1, value),
+        }
+    }
+
+    fn remove(&mut self, key: &H256) -> Result<()> {
+        match self.read_counted_thing(key)? {
+            None => Ok(()),
+            Some((1, _)) => fs::remove_file(self.get_path_from_key(key)).map_err(|e| e.into()),
+            Some((count, thing)) => self.write_counted_thing(key, count - 1, thing),
+        }
+    }
+
+    fn contains(&self, key: &H256) -> Result<bool> {
+        Ok(self.get_path_from_key(key).is_file())
+    }
+
+    fn get_reference_count(&self, key: &H256) -> Result<usize> {
+        self.read_counted_thing(key)
+            .map(|counted_thing| counted_thing.map(|(count, _)| count).unwrap_or(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_database::get_new_database;
+    use crate::test_utils::{
+        get_expected_key_of_thing_in_database, get_temporary_directory,
+        get_thing_to_put_in_database,
+    };
+
+    fn should_put_get_and_remove_thing_in_store<D: NodeStore>(mut store: D) {
+        let key = get_expected_key_of_thing_in_database();
+        let thing = get_thing_to_put_in_database();
+        assert!(store.get(&key).unwrap().is_none());
+        assert!(!store.contains(&key).unwrap());
+        store.put(key, thing.clone()).unwrap();
+        assert!(store.contains(&key).unwrap());
+        assert!(store.get(&key).unwrap() == Some(thing));
+        store.remove(&key).unwrap();
+        assert!(!store.contains(&key).unwrap());
+        assert!(store.get(&key).unwrap().is_none());
+        store.remove(&key).unwrap();
+    }
+
+    fn should_keep_thing_in_store_until_every_reference_removed<D: NodeStore>(mut store: D) {
+        let key = get_expected_key_of_thing_in_database();
+        let thing = get_thing_to_put_in_database();
+        store.put(key, thing.clone()).unwrap();
+        store.put(key, thing.clone()).unwrap();
+        assert!(store.get_reference_count(&key).unwrap() == 2);
+        store.remove(&key).unwrap();
+        assert!(store.get_reference_count(&key).unwrap() == 1);
+        assert!(store.get(&key).unwrap() == Some(thing));
+        store.remove(&key).unwrap();
+        assert!(store.get_reference_count(&key).unwrap() == 0);
+        assert!(!store.contains(&key).unwrap());
+    }
+
+    #[test]
+    fn should_put_get_and_remove_thing_in_memory_store() {
+        should_put_get_and_remove_thing_in_store(get_new_database().unwrap());
+    }
+
+    #[test]
+    fn should_put_get_and_remove_thing_in_file_store() {
+        let directory = get_temporary_directory("file-store");
+        should_put_get_and_remove_thing_in_store(FileNodeStore::new(&directory).unwrap());
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_keep_thing_in_memory_store_until_every_reference_removed() {
+        should_keep_thing_in_store_until_every_reference_removed(get_new_database().unwrap());
+    }
+
+    #[test]
+    fn should_keep_thing_in_file_store_until_every_reference_removed() {
+        let directory = get_temporary_directory("file-store-references");
+        should_keep_thing_in_store_until_every_reference_removed(
+            FileNodeStore::new(&directory).unwrap(),
+        );
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_keep_reference_counts_when_reopening_file_store() {
+        let directory = get_temporary_directory("file-store-reopened-references");
+        let key = get_expected_key_of_thing_in_database();
+        let mut store = FileNodeStore::new(&directory).unwrap();
+        store.put(key, get_thing_to_put_in_database()).unwrap();
+        store.put(key, get_thing_to_put_in_database()).unwrap();
+        let reopened_store = FileNodeStore::new(&directory).unwrap();
+        assert!(reopened_store.get_reference_count(&key).unwrap() == 2);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_create_file_store_directory() {
+        let directory = get_temporary_directory("new-file-store").join("nested");
+        assert!(!directory.exists());
+        FileNodeStore::new(&directory).unwrap();
+        assert!(directory.is_dir());
+        fs::remove_dir_all(directory.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn should_name_file_store_files_by_hex_hash() {
+        let directory = get_temporary_directory("file-store-names");
+        let mut store = FileNodeStore::new(&directory).unwrap();
+        let key = get_expected_key_of_thing_in_database();
+        store.put(key, get_thing_to_put_in_database()).unwrap();
+        assert!(directory.join(hex::encode(key)).is_file());
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
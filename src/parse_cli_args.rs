@@ -2,13 +2,25 @@ use docopt::Docopt;
 use log::LevelFilter;
 use simplelog::*;
 
-use crate::{errors::AppError, types::Result, usage_info::USAGE_INFO, utils::convert_hex_to_h256};
+use crate::usage_info::USAGE_INFO;
+use rusty_receipt_proof_maker::{convert_hex_to_h256, AppError, Result};
 
 #[allow(non_snake_case)]
 #[derive(Debug, Deserialize)]
 pub struct CliArgs {
+    pub cmd_tx: bool,
+    pub cmd_bundle: bool,
+    pub cmd_verify: bool,
+    pub cmd_account: bool,
+    pub flag_header: bool,
     pub flag_verbose: bool,
     pub arg_txhash: String,
+    pub arg_proof: String,
+    pub arg_receiptsroot: String,
+    pub arg_index: String,
+    pub arg_address: String,
+    pub arg_storagekey: Vec<String>,
+    pub flag_block: String,
 }
 
 pub fn parse_cli_args() -> Result<CliArgs> {
@@ -25,10 +37,14 @@ pub fn parse_cli_args() -> Result<CliArgs> {
             )?;
             info!("✔ CLI Args parsed successfully!");
             info!("✔ Verbose mode: {}", cli_args.flag_verbose);
-            info!(
-                "✔ Transaction hash: {}",
-                convert_hex_to_h256(cli_args.arg_txhash.clone())?
-            );
+            match (cli_args.cmd_verify, cli_args.cmd_account) {
+                (true, _) => info!("✔ Receipt index: {}", cli_args.arg_index),
+                (false, true) => info!("✔ Account address: {}", cli_args.arg_address),
+                (false, false) => info!(
+                    "✔ Transaction hash: {}",
+                    convert_hex_to_h256(cli_args.arg_txhash.clone())?
+                ),
+            };
             Ok(cli_args)
         }
         Err(e) => Err(AppError::Custom(e.to_string())),
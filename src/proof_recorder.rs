@@ -0,0 +1,239 @@
+use crate::node_store::NodeStore;
+use crate::trie::Trie;
+use crate::types::{Bytes, Database, Result};
+use ethereum_types::H256;
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+/**
+ *
+ * The Proof Recorder:
+ *
+ * Wraps a node store & records every node read from it, in the order they're
+ * first read, so that whatever a computation over a trie needed can be
+ * emitted as a witness. Any number of `get`s, `find`s & even writes can be
+ * run over the trie, with the witness being exactly those nodes of the trie
+ * as it stood beforehand which were touched along the way.
+ *
+ * Nodes written during recording, and not already in the store before then,
+ * are recreated by replaying the same writes, and so aren't recorded when
+ * read back. Writing a node identical to one already stored doesn't count,
+ * since once the write is undone only the original remains to be read.
+ * Inline nodes are never read from the store, living within their parents,
+ * and so are covered by recording those.
+ *
+ */
+#[derive(Clone, Debug)]
+pub struct ProofRecorder<D: NodeStore> {
+    pub store: D,
+    recorded_hashes: RefCell<Vec<H256>>,
+    recorded_nodes: RefCell<Database>,
+    created_hashes: HashSet<H256>,
+}
+
+impl<D: NodeStore> ProofRecorder<D> {
+    pub fn new(store: D) -> ProofRecorder<D> {
+        ProofRecorder {
+            store,
+            recorded_hashes: RefCell::new(Vec::new()),
+            recorded_nodes: RefCell::new(Database::new()),
+            created_hashes: HashSet::new(),
+        }
+    }
+
+    pub fn get_recorded_hashes(&self) -> Vec<H256> {
+        self.recorded_hashes.borrow().clone()
+    }
+
+    pub fn get_witness(&self) -> Vec<Bytes> {
+        let recorded_nodes = self.recorded_nodes.borrow();
+        self.recorded_hashes
+            .borrow()
+            .iter()
+            .filter_map(|hash| recorded_nodes.get(hash).cloned())
+            .collect()
+    }
+
+    fn record_node(&self, key: &H256, node: &[u8]) {
+        if !self.created_hashes.contains(key) && !self.recorded_nodes.borrow().contains_key(key) {
+            trace!("Recording node read from store w/ hash: {:?}", key);
+            self.recorded_hashes.borrow_mut().push(*key);
+            self.recorded_nodes.borrow_mut().insert(*key, node.to_vec());
+        }
+    }
+}
+
+impl<D: NodeStore> NodeStore for ProofRecorder<D> {
+    fn get(&self, key: &H256) -> Result<Option<Bytes>> {
+        let maybe_node = self.store.get(key)?;
+        if let Some(node) = &maybe_node {
+            self.record_node(key, node)
+        };
+        Ok(maybe_node)
+    }
+
+    fn put(&mut self, key: H256, value: Bytes) -> Result<()> {
+        if !self.store.contains(&key)? {
+            self.created_hashes.insert(key);
+        };
+        self.store.put(key, value)
+    }
+
+    fn remove(&mut self, key: &H256) -> Result<()> {
+        self.store.remove(key)
+    }
+
+    fn contains(&self, key: &H256) -> Result<bool> {
+        self.store.contains(key)
+    }
+
+    fn get_reference_count(&self, key: &H256) -> Result<usize> {
+        self.store.get_reference_count(key)
+    }
+}
+
+pub fn get_recording_trie<D: NodeStore>(trie: Trie<D>) -> Result<Trie<ProofRecorder<D>>> {
+    Ok(Trie {
+        root: trie.root,
+        database: ProofRecorder::new(trie.database),
+        secure: trie.secure,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::HASH_LENGTH;
+    use crate::get_branch_from_trie::{
+        convert_usize_index_to_trie_key, get_branch_from_trie, get_branches_from_trie,
+    };
+    use crate::nibble_utils::get_nibbles_from_bytes;
+    use crate::node_store::MemoryNodeStore;
+    use crate::test_utils::{
+        get_rlp_encoded_nodes_from_node_stack, get_sample_indexed_value,
+        get_sample_receipts_1_trie_and_key_value_tuples, get_sample_trie_with_inline_nodes,
+    };
+
+    fn get_sample_trie() -> Trie {
+        get_sample_receipts_1_trie_and_key_value_tuples().0
+    }
+
+    fn get_sample_recording_trie() -> Trie<ProofRecorder<MemoryNodeStore>> {
+        get_recording_trie(get_sample_trie()).unwrap()
+    }
+
+    #[test]
+    fn should_record_nothing_before_reading() {
+        let trie = get_sample_recording_trie();
+        assert!(trie.database.get_recorded_hashes().is_empty());
+        assert!(trie.database.get_witness().is_empty());
+    }
+
+    #[test]
+    fn should_record_branch_of_single_get() {
+        let index = 14;
+        let expected_witness = get_rlp_encoded_nodes_from_node_stack(
+            &get_branch_from_trie(&get_sample_trie(), index).unwrap(),
+        );
+        let trie = get_sample_recording_trie();
+        trie.get(convert_usize_index_to_trie_key(index).unwrap())
+            .unwrap();
+        assert!(trie.database.get_witness() == expected_witness);
+    }
+
+    #[test]
+    fn should_record_each_node_once_across_several_reads() {
+        let indices = [3, 14, 20, 14];
+        let expected_witness = get_rlp_encoded_nodes_from_node_stack(
+            &get_branches_from_trie(&get_sample_trie(), &indices).unwrap(),
+        );
+        let trie = get_sample_recording_trie();
+        indices.iter().for_each(|index| {
+            let key = convert_usize_index_to_trie_key(*index).unwrap();
+            trie.get(key.clone()).unwrap();
+            trie.find(key).unwrap();
+        });
+        assert!(trie.database.get_witness() == expected_witness);
+    }
+
+    #[test]
+    fn should_not_record_inline_nodes() {
+        let trie = get_recording_trie(get_sample_trie_with_inline_nodes()).unwrap();
+        trie.get(get_nibbles_from_bytes(b"dog".to_vec())).unwrap();
+        let witness = trie.database.get_witness();
+        assert!(!witness.is_empty());
+        assert!(witness.len() == trie.database.get_recorded_hashes().len());
+        assert!(witness.iter().all(|node| node.len() >= HASH_LENGTH));
+    }
+
+    #[test]
+    fn should_answer_recorded_reads_from_partial_trie_of_witness() {
+        let indices = [0, 7, 24];
+        let trie = get_sample_recording_trie();
+        let values = indices
+            .iter()
+            .map(|index| trie.get(convert_usize_index_to_trie_key(*index).unwrap()))
+            .collect::<Result<Vec<Option<Bytes>>>>()
+            .unwrap();
+        let partial_trie =
+            Trie::get_partial_trie_from_proof_nodes(trie.root, &trie.database.get_witness())
+                .unwrap();
+        indices.iter().zip(values).for_each(|(index, value)| {
+            let key = convert_usize_index_to_trie_key(*index).unwrap();
+            assert!(partial_trie.get(key).unwrap() == value);
+        });
+    }
+
+    #[test]
+    fn should_record_only_prior_nodes_when_writing() {
+        let indices = [3, 14];
+        let mut trie = get_sample_recording_trie();
+        let root_before = trie.root;
+        indices.iter().for_each(|index| {
+            let key = convert_usize_index_to_trie_key(*index).unwrap();
+            trie.put(key.clone(), get_sample_indexed_value(*index))
+                .unwrap();
+            trie.get(key).unwrap();
+        });
+        let mut partial_trie =
+            Trie::get_partial_trie_from_proof_nodes(root_before, &trie.database.get_witness())
+                .unwrap();
+        assert!(partial_trie.database.nodes.len() == trie.database.get_witness().len());
+        indices.iter().for_each(|index| {
+            let key = convert_usize_index_to_trie_key(*index).unwrap();
+            partial_trie
+                .put(key, get_sample_indexed_value(*index))
+                .unwrap();
+        });
+        assert!(partial_trie.root == trie.root);
+    }
+
+    #[test]
+    fn should_record_unread_node_identical_to_one_written() {
+        let unread_key = get_nibbles_from_bytes(vec![0x15, 0x55]);
+        let written_key = get_nibbles_from_bytes(vec![0x25, 0x55]);
+        let mut trie = Trie::get_new_trie().unwrap();
+        trie.put(unread_key.clone(), get_sample_indexed_value(0))
+            .unwrap();
+        trie.put(
+            get_nibbles_from_bytes(vec![0x35, 0x55]),
+            get_sample_indexed_value(1),
+        )
+        .unwrap();
+        let mut trie = get_recording_trie(trie).unwrap();
+        let root_before = trie.root;
+        trie.put(written_key.clone(), get_sample_indexed_value(0))
+            .unwrap();
+        trie.delete(written_key.clone()).unwrap();
+        let value = trie.get(unread_key.clone()).unwrap();
+        let mut partial_trie =
+            Trie::get_partial_trie_from_proof_nodes(root_before, &trie.database.get_witness())
+                .unwrap();
+        partial_trie
+            .put(written_key.clone(), get_sample_indexed_value(0))
+            .unwrap();
+        partial_trie.delete(written_key).unwrap();
+        assert!(partial_trie.get(unread_key).unwrap() == value);
+        assert!(partial_trie.root == trie.root);
+    }
+}
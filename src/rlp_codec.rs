@@ -1,12 +1,81 @@
+use crate::constants::LEGACY_TX_TYPE;
+use crate::errors::AppError;
 use crate::nibble_utils::{get_nibbles_from_bytes, Nibbles};
-use crate::types::{Bytes, Receipt, Result};
+use crate::types::{Account, Block, BlockHeader, Byte, Bytes, Receipt, Result, Transaction};
 use ethereum_types::U256;
-use rlp::RlpStream;
+use rlp::{DecoderError, Rlp, RlpStream};
 
 fn rlp_encode_receipt(receipt: &Receipt) -> Result<Bytes> {
     let mut rlp_stream = RlpStream::new();
     rlp_stream.append(receipt);
-    Ok(rlp_stream.out())
+    match receipt.tx_type {
+        LEGACY_TX_TYPE => Ok(rlp_stream.out()),
+        tx_type => Ok([vec![tx_type], rlp_stream.out()].concat()),
+    }
+}
+
+fn rlp_encode_transaction(transaction: &Transaction) -> Result<Bytes> {
+    let mut rlp_stream = RlpStream::new();
+    rlp_stream.append(transaction);
+    match transaction.tx_type {
+        LEGACY_TX_TYPE => Ok(rlp_stream.out()),
+        tx_type => Ok([vec![tx_type], rlp_stream.out()].concat()),
+    }
+}
+
+fn check_header_fork_fields_are_consistent(block: &Block) -> Result<()> {
+    let fork_fields_present = [
+        block.base_fee_per_gas.is_some(),
+        block.withdrawals_root.is_some(),
+        block.blob_gas_used.is_some(),
+        block.excess_blob_gas.is_some(),
+        block.parent_beacon_block_root.is_some(),
+        block.requests_hash.is_some(),
+    ];
+    match fork_fields_present
+        .windows(2)
+        .all(|pair| pair[0] || !pair[1])
+        && block.blob_gas_used.is_some() == block.excess_blob_gas.is_some()
+    {
+        true => Ok(()),
+        false => Err(AppError::Custom(format!(
+            "✘ Block {:?} has an inconsistent set of fork-specific header fields!",
+            block.hash
+        ))),
+    }
+}
+
+pub fn rlp_encode_block_header(block: &Block) -> Result<Bytes> {
+    check_header_fork_fields_are_consistent(block).map(|_| {
+        let mut rlp_stream = RlpStream::new();
+        rlp_stream.append(&BlockHeader(block));
+        rlp_stream.out()
+    })
+}
+
+fn convert_rlp_decoder_error(e: DecoderError) -> AppError {
+    AppError::Custom(e.to_string())
+}
+
+pub fn rlp_decode_account(rlp_encoded_account: &[Byte]) -> Result<Account> {
+    let rlp = Rlp::new(rlp_encoded_account);
+    match rlp.item_count() {
+        Ok(4) => Ok(Account {
+            nonce: rlp.val_at(0).map_err(convert_rlp_decoder_error)?,
+            balance: rlp.val_at(1).map_err(convert_rlp_decoder_error)?,
+            storage_root: rlp.val_at(2).map_err(convert_rlp_decoder_error)?,
+            code_hash: rlp.val_at(3).map_err(convert_rlp_decoder_error)?,
+        }),
+        _ => Err(AppError::Custom(
+            "✘ RLP-encoded account is not a list of 4 items!".to_string(),
+        )),
+    }
+}
+
+pub fn rlp_decode_storage_value(rlp_encoded_value: &[Byte]) -> Result<U256> {
+    Rlp::new(rlp_encoded_value)
+        .as_val()
+        .map_err(convert_rlp_decoder_error)
 }
 
 pub fn rlp_encode_transaction_index(index: &U256) -> Result<Bytes> {
@@ -35,10 +104,52 @@ pub fn get_rlp_encoded_receipts_and_nibble_tuples(
         .collect::<Result<Vec<(Nibbles, Bytes)>>>()
 }
 
+pub fn get_rlp_encoded_transaction_and_encoded_key_tuple(
+    transaction: &Transaction,
+) -> Result<(Nibbles, Bytes)> {
+    rlp_encode_transaction(transaction).and_then(|rlp_encoded_transaction| {
+        Ok((
+            get_nibbles_from_bytes(rlp_encode_transaction_index(
+                &transaction.transaction_index,
+            )?),
+            rlp_encoded_transaction,
+        ))
+    })
+}
+
+pub fn get_rlp_encoded_transactions_and_nibble_tuples(
+    transactions: &[Transaction],
+) -> Result<Vec<(Nibbles, Bytes)>> {
+    transactions
+        .iter()
+        .map(get_rlp_encoded_transaction_and_encoded_key_tuple)
+        .collect::<Result<Vec<(Nibbles, Bytes)>>>()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::{get_expected_receipt, get_expected_receipt_2, get_expected_receipt_3};
+    use crate::get_keccak_hash::keccak_hash_bytes;
+    use crate::test_utils::{
+        get_expected_block, get_expected_receipt, get_expected_receipt_2, get_expected_receipt_3,
+        get_sample_prague_block, get_sample_transactions,
+    };
+    use crate::types::ReceiptOutcome;
+    use ethereum_types::H256;
+
+    fn get_expected_rlp_encoded_transactions() -> Vec<Bytes> {
+        // NOTE: One tx of each type, 0 through 4. The first is EIP-155's example tx.
+        [
+            "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83",
+            "01f90107011f843b9aca0083010d8894de0b295669a9fd93d5f28d9ec85e40f4cb697bae80b844a9059cbb0000000000000000000000005aaeb6053f3e94c9b9a09f33669435e7ef1beaed0000000000000000000000000000000000000000000000000de0b6b3a7640000f85bf85994de0b295669a9fd93d5f28d9ec85e40f4cb697baef842a00000000000000000000000000000000000000000000000000000000000000003a0000000000000000000000000000000000000000000000000000000000000000701a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636277a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d84",
+            "02f891012a8459682f008512a05f200082520880872386f26fc10000b36080604052348015600f57600080fd5b50603f80601d6000396000f3fe6080604052600080fdfea164736f6c6343000819000ac080a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636278a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d85",
+            "03f8b30107843b9aca00847735940082520894ff000000000000000000000000000000000000008080c0843b9aca00f842a001a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a001b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b201a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636279a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d86",
+            "04f8c90103843b9aca008477359400830186a0945aaeb6053f3e94c9b9a09f33669435e7ef1beaed8080c0f85cf85a019463c0c19a282a1b52b07dd5a65b58948a07dae32b0480a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa63627ba067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d8880a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa63627aa067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d87",
+        ]
+        .iter()
+        .map(|hex| hex::decode(hex).unwrap())
+        .collect()
+    }
 
     fn get_expected_receipt_nibbles() -> Nibbles {
         get_nibbles_from_bytes(vec![0x60])
@@ -187,6 +298,62 @@ mod tests {
         assert!(result == get_rlp_encoded_receipt_3())
     }
 
+    #[test]
+    fn should_rlp_encode_typed_receipt_with_type_byte_prefix() {
+        let mut receipt = get_expected_receipt();
+        receipt.tx_type = 2;
+        let expected_result = [vec![0x02], get_rlp_encoded_receipt_1()].concat();
+        let result = rlp_encode_receipt(&receipt).unwrap();
+        assert!(result == expected_result)
+    }
+
+    #[test]
+    fn should_rlp_encode_pre_byzantium_receipt_with_post_state_root() {
+        let root = H256::from_slice(&[0xab; 32]);
+        let mut receipt = get_expected_receipt();
+        receipt.outcome = ReceiptOutcome::PostStateRoot(root);
+        let mut rlp_stream = RlpStream::new();
+        rlp_stream
+            .begin_list(4)
+            .append(&root)
+            .append(&receipt.cumulative_gas_used)
+            .append(&receipt.logs_bloom)
+            .append_list(&receipt.logs);
+        let expected_result = rlp_stream.out();
+        let result = rlp_encode_receipt(&receipt).unwrap();
+        assert!(result == expected_result)
+    }
+
+    #[test]
+    fn should_rlp_encode_legacy_and_typed_transactions() {
+        get_sample_transactions()
+            .iter()
+            .zip(get_expected_rlp_encoded_transactions().iter())
+            .for_each(|(tx, expected_result)| {
+                let result = rlp_encode_transaction(tx).unwrap();
+                assert!(&result == expected_result);
+            });
+    }
+
+    #[test]
+    fn should_rlp_encode_transactions_to_preimages_of_their_hashes() {
+        get_sample_transactions().iter().for_each(|tx| {
+            let result = rlp_encode_transaction(tx).unwrap();
+            assert!(keccak_hash_bytes(&result).unwrap() == tx.hash);
+        });
+    }
+
+    #[test]
+    fn should_get_encoded_transactions_and_nibble_tuples() {
+        let transactions = get_sample_transactions();
+        let results = get_rlp_encoded_transactions_and_nibble_tuples(&transactions).unwrap();
+        results.iter().enumerate().for_each(|(i, result)| {
+            let expected_key = rlp_encode_transaction_index(&U256::from(i)).unwrap();
+            assert!(result.0 == get_nibbles_from_bytes(expected_key));
+            assert!(result.1 == get_expected_rlp_encoded_transactions()[i]);
+        });
+    }
+
     #[test]
     fn should_get_encoded_receipt_and_hash_tuple() {
         let result =
@@ -208,6 +375,72 @@ mod tests {
             .for_each(drop);
     }
 
+    #[test]
+    fn should_rlp_encode_block_header_to_preimage_of_block_hash() {
+        let block = get_expected_block();
+        let result = rlp_encode_block_header(&block).unwrap();
+        assert!(keccak_hash_bytes(&result).unwrap() == block.hash);
+    }
+
+    #[test]
+    fn should_rlp_encode_each_fork_field_present_in_block_header() {
+        let block = get_sample_prague_block();
+        let result = rlp_encode_block_header(&block).unwrap();
+        let rlp = Rlp::new(&result);
+        assert!(rlp.item_count().unwrap() == 21);
+        assert!(rlp.val_at::<U256>(15).unwrap() == block.base_fee_per_gas.unwrap());
+        assert!(rlp.val_at::<H256>(20).unwrap() == block.requests_hash.unwrap());
+    }
+
+    #[test]
+    fn should_err_when_rlp_encoding_block_header_with_fork_field_gap() {
+        let mut block = get_sample_prague_block();
+        block.withdrawals_root = None;
+        let expected_err = format!(
+            "✘ Block {:?} has an inconsistent set of fork-specific header fields!",
+            block.hash
+        );
+        match rlp_encode_block_header(&block) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Header with a fork field gap should not encode!"),
+        }
+    }
+
+    #[test]
+    fn should_rlp_decode_account() {
+        let expected_result = Account {
+            nonce: U256::from(7),
+            balance: U256::from(1337),
+            storage_root: H256::from_low_u64_be(1),
+            code_hash: H256::from_low_u64_be(2),
+        };
+        let mut rlp_stream = RlpStream::new_list(4);
+        rlp_stream
+            .append(&expected_result.nonce)
+            .append(&expected_result.balance)
+            .append(&expected_result.storage_root)
+            .append(&expected_result.code_hash);
+        let result = rlp_decode_account(&rlp_stream.out()).unwrap();
+        assert!(result == expected_result);
+    }
+
+    #[test]
+    fn should_err_rlp_decoding_account_with_wrong_number_of_items() {
+        let expected_err = "✘ RLP-encoded account is not a list of 4 items!";
+        let mut rlp_stream = RlpStream::new_list(2);
+        rlp_stream.append(&U256::one()).append(&U256::one());
+        match rlp_decode_account(&rlp_stream.out()) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Account should not have decoded!"),
+        }
+    }
+
+    #[test]
+    fn should_rlp_decode_storage_value() {
+        let result = rlp_decode_storage_value(&[0x83, 0xc0, 0xff, 0xee]).unwrap();
+        assert!(result == U256::from(0xc0ffee));
+    }
+
     #[test]
     fn should_encode_tx_receipt() {
         let index_u256 = U256::from_dec_str("3").unwrap();
@@ -1,6 +1,6 @@
 use crate::errors::AppError;
 use crate::trie::Trie;
-use crate::types::{Block, Database, NodeStack, Receipt, Result};
+use crate::types::{Block, Database, NodeStack, Receipt, Result, Transaction};
 use crate::utils::{get_no_overwrite_state_err, get_not_in_state_err};
 use ethereum_types::H256;
 
@@ -14,6 +14,9 @@ pub struct State {
     pub branch: Option<NodeStack>,
     pub receipts_trie: Option<Trie>,
     pub receipts: Option<Vec<Receipt>>,
+    pub transactions_trie: Option<Trie>,
+    pub transaction_branch: Option<NodeStack>,
+    pub transactions: Option<Vec<Transaction>>,
 }
 
 impl State {
@@ -27,6 +30,9 @@ impl State {
             receipts: None,
             tx_hash_string,
             receipts_trie: None,
+            transactions: None,
+            transactions_trie: None,
+            transaction_branch: None,
             database: std::collections::HashMap::new(),
         })
     }
@@ -93,6 +99,40 @@ impl State {
         }
     }
 
+    pub fn set_transactions_in_state(mut self, transactions: Vec<Transaction>) -> Result<State> {
+        match self.transactions {
+            Some(_) => Err(AppError::Custom(get_no_overwrite_state_err("transactions"))),
+            None => {
+                self.transactions = Some(transactions);
+                Ok(self)
+            }
+        }
+    }
+
+    pub fn set_transactions_trie_in_state(mut self, transactions_trie: Trie) -> Result<State> {
+        match self.transactions_trie {
+            Some(_) => Err(AppError::Custom(get_no_overwrite_state_err(
+                "transactions_trie",
+            ))),
+            None => {
+                self.transactions_trie = Some(transactions_trie);
+                Ok(self)
+            }
+        }
+    }
+
+    pub fn set_transaction_branch_in_state(mut self, branch: NodeStack) -> Result<State> {
+        match self.transaction_branch {
+            Some(_) => Err(AppError::Custom(get_no_overwrite_state_err(
+                "transaction_branch",
+            ))),
+            None => {
+                self.transaction_branch = Some(branch);
+                Ok(self)
+            }
+        }
+    }
+
     pub fn get_block_from_state(&self) -> Result<&Block> {
         match &self.block {
             Some(block) => Ok(block),
@@ -134,6 +174,27 @@ impl State {
             None => Err(AppError::Custom(get_not_in_state_err("receipts_trie"))),
         }
     }
+
+    pub fn get_transactions_from_state(&self) -> Result<&Vec<Transaction>> {
+        match &self.transactions {
+            Some(transactions) => Ok(transactions),
+            None => Err(AppError::Custom(get_not_in_state_err("transactions"))),
+        }
+    }
+
+    pub fn get_transactions_trie_from_state(&self) -> Result<&Trie> {
+        match &self.transactions_trie {
+            Some(transactions_trie) => Ok(transactions_trie),
+            None => Err(AppError::Custom(get_not_in_state_err("transactions_trie"))),
+        }
+    }
+
+    pub fn get_transaction_branch_from_state(&self) -> Result<&NodeStack> {
+        match &self.transaction_branch {
+            Some(branch) => Ok(branch),
+            None => Err(AppError::Custom(get_not_in_state_err("transaction_branch"))),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -141,7 +202,8 @@ mod tests {
     use super::*;
     use crate::test_utils::{
         assert_block_is_correct, assert_receipt_is_correct, get_expected_block,
-        get_expected_receipt, get_valid_initial_state, get_valid_tx_hash_h256,
+        get_expected_receipt, get_sample_transactions, get_valid_initial_state,
+        get_valid_tx_hash_h256,
     };
 
     #[test]
@@ -331,4 +393,73 @@ mod tests {
             _ => panic!("Overwriting state should not have succeeded!"),
         }
     }
+
+    #[test]
+    fn initial_state_should_have_no_transactions_trie() {
+        let expected_err = get_not_in_state_err("transactions_trie");
+        let state = get_valid_initial_state().unwrap();
+        match State::get_transactions_trie_from_state(&state) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Transactions trie should not be initialised in state!"),
+        }
+    }
+
+    #[test]
+    fn should_set_transactions_in_state() {
+        let transactions = get_sample_transactions();
+        let state = get_valid_initial_state().unwrap();
+        let new_state = State::set_transactions_in_state(state, transactions.clone()).unwrap();
+        let result = State::get_transactions_from_state(&new_state).unwrap();
+        assert!(result.len() == transactions.len());
+        result
+            .iter()
+            .zip(transactions.iter())
+            .for_each(|(tx, expected_tx)| assert!(tx.hash == expected_tx.hash));
+    }
+
+    #[test]
+    fn should_err_when_attempting_to_overwrite_transactions_in_state() {
+        let expected_err = "✘ Cannot overwrite transactions in state!";
+        let transactions = get_sample_transactions();
+        let state = get_valid_initial_state().unwrap();
+        let state_with_transactions =
+            State::set_transactions_in_state(state, transactions.clone()).unwrap();
+        match State::set_transactions_in_state(state_with_transactions, transactions) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Overwriting state should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_set_transactions_trie_in_state() {
+        let trie = Trie::get_new_trie().unwrap();
+        let expected_root = trie.root;
+        let state = get_valid_initial_state().unwrap();
+        let new_state = State::set_transactions_trie_in_state(state, trie).unwrap();
+        let result = State::get_transactions_trie_from_state(&new_state).unwrap();
+        assert!(result.root == expected_root);
+    }
+
+    #[test]
+    fn should_set_transaction_branch_in_state() {
+        let expected_branch: NodeStack = Vec::new();
+        let state = get_valid_initial_state().unwrap();
+        let new_state =
+            State::set_transaction_branch_in_state(state, expected_branch.clone()).unwrap();
+        let result = State::get_transaction_branch_from_state(&new_state).unwrap();
+        assert!(result == &expected_branch);
+    }
+
+    #[test]
+    fn should_err_when_attempting_to_overwrite_transaction_branch_in_state() {
+        let expected_err = "✘ Cannot overwrite transaction_branch in state!";
+        let branch: NodeStack = Vec::new();
+        let state = get_valid_initial_state().unwrap();
+        let state_with_branch =
+            State::set_transaction_branch_in_state(state, branch.clone()).unwrap();
+        match State::set_transaction_branch_in_state(state_with_branch, branch) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Overwriting state should not have succeeded!"),
+        }
+    }
 }
@@ -0,0 +1,14 @@
+#![cfg(test)]
+#![allow(dead_code)] // NOTE: The binary uses only some of these.
+
+// NOTE: Fixtures shared by the library's & the binary's tests, so neither drifts from the other.
+pub const PROOF_1_INDEX: usize = 14;
+pub const SAMPLE_PROOF_1_PATH: &str = "./test_utils/sample_proof_1";
+pub const SAMPLE_ACCOUNT_PROOF_JSON_PATH: &str = "./test_utils/sample_account_proof_json";
+pub const RECEIPTS_ROOT_1: &str =
+    "0x937e08f03388b32d7c776e7a02371b930d71e3ec096d495230b6735e7f9b20ae";
+pub const RECEIPTS_ROOT_2: &str =
+    "0x2521aac56061222f09f10ffcc63665ced543549f1b69e1dbc7cb0b4c705d390f";
+pub const RECEIPTS_ROOT_3: &str =
+    "0x4c9bb7d6a6c74445c15e5915262c49c69cd14b3e19620302f2c10303fef1e392";
+pub const STATE_ROOT: &str = "0x43cf718c51e51d7118100ee803bdf393cb00dc43536739b4b545562d25947652";
@@ -2,44 +2,60 @@
 #![allow(unused_imports)]
 
 use crate::constants::{DEFAULT_ENDPOINT, DOT_ENV_PATH};
+use crate::get_account_proof::get_account_proof_from_rpc_response;
 use crate::get_block::deserialize_block_json_to_block_struct;
-use crate::get_branch_from_trie::get_branch_from_trie_and_put_in_state;
+use crate::get_branch_from_trie::{
+    convert_usize_index_to_trie_key, get_branch_from_trie_and_put_in_state,
+};
 use crate::get_database::put_thing_in_database;
+use crate::get_hex_proof_from_branch::rlp_encode_node_stack;
 use crate::get_receipts::deserialize_receipt_json_to_receipt_struct;
+use crate::get_transactions::deserialize_transaction_json_to_transaction_struct;
+use crate::get_transactions_trie::get_transactions_trie_and_set_in_state;
 use crate::make_rpc_call::{
     deserialize_to_block_rpc_response, deserialize_to_receipt_rpc_response,
+    deserialize_to_transaction_rpc_response,
 };
 use crate::nibble_utils::{get_nibbles_from_bytes, get_nibbles_from_offset_bytes, Nibbles};
+use crate::node_store::MemoryNodeStore;
 use crate::rlp_codec::get_rlp_encoded_receipts_and_nibble_tuples;
 use crate::state::State;
 use crate::trie::{put_in_trie_recursively, Trie};
 use crate::trie_nodes::Node;
-use crate::types::{Block, Bytes, Database, Log, Receipt, Result};
+use crate::types::{AccountProof, Block, Bytes, Database, Log, Receipt, Result, Transaction};
 use crate::utils::{convert_h256_to_prefixed_hex, convert_hex_to_h256};
 use ethereum_types::H256;
+use rlp::Rlp;
 use std::fs;
 
+pub use crate::test_fixtures::{
+    PROOF_1_INDEX, RECEIPTS_ROOT_1, RECEIPTS_ROOT_2, RECEIPTS_ROOT_3,
+    SAMPLE_ACCOUNT_PROOF_JSON_PATH, SAMPLE_PROOF_1_PATH, STATE_ROOT,
+};
+
 pub const TX_INDEX: usize = 96;
-pub const PROOF_1_INDEX: usize = 14;
 pub const PROOF_3_INDEX: usize = 134;
 pub const WORKING_ENDPOINT: &str = "http://localhost:8545";
 pub const SAMPLE_BLOCK_JSON_PATH: &str = "./test_utils/sample_block_json";
+pub const SAMPLE_PRAGUE_BLOCK_JSON_PATH: &str = "./test_utils/sample_prague_block_json";
 pub const SAMPLE_RECEIPT_JSON_PATH: &str = "./test_utils/sample_receipt_json";
 pub const SAMPLE_RECEIPT_JSON_PATH_2: &str = "./test_utils/sample_receipt_json_2";
 pub const SAMPLE_RECEIPT_JSON_PATH_3: &str = "./test_utils/sample_receipt_json_3";
+pub const SAMPLE_CLIENT_JSONS_PATH: &str = "./test_utils/sample_client_jsons/";
+pub const SAMPLE_CLIENT_NAMES: [&str; 5] = ["geth", "erigon", "nethermind", "reth", "anvil"];
+pub const SAMPLE_CLIENTS_WITH_TOTAL_DIFFICULTY: [&str; 3] = ["erigon", "nethermind", "anvil"];
+pub const SAMPLE_TRANSACTION_JSONS_PATH: &str = "./test_utils/sample_transaction_jsons/";
 pub const SAMPLE_RECEIPT_JSONS_1_PATH: &str = "./test_utils/sample_receipt_jsons_1/";
 pub const SAMPLE_RECEIPT_JSONS_2_PATH: &str = "./test_utils/sample_receipt_jsons_2/";
 pub const SAMPLE_RECEIPT_JSONS_3_PATH: &str = "./test_utils/sample_receipt_jsons_3/";
 pub const SAMPLE_TX_HASH: &str =
     "0xd6f577a93332e015438fcca4e73f538b1829acbd7eb0cf9ee5a0a73ff2752cc6";
-pub const RECEIPTS_ROOT_1: &str =
-    "0x937e08f03388b32d7c776e7a02371b930d71e3ec096d495230b6735e7f9b20ae";
-pub const RECEIPTS_ROOT_2: &str =
-    "0x2521aac56061222f09f10ffcc63665ced543549f1b69e1dbc7cb0b4c705d390f";
-pub const RECEIPTS_ROOT_3: &str =
-    "0x4c9bb7d6a6c74445c15e5915262c49c69cd14b3e19620302f2c10303fef1e392";
+pub const TRANSACTIONS_ROOT: &str =
+    "0x69e74cdde15fd5cd6f1d6ccbc4a995f18c6823b85738af13b4ad78db455064f2";
 pub const SAMPLE_BLOCK_HASH: &str =
     "0x1ddd540f36ea0ed23e732c1709a46c31ba047b98f1d99e623f1644154311fe10";
+pub const INLINE_TRIE_ROOT: &str =
+    "0x5991bb8c6514148a29db676a14ac506cd2cd5775ace63c30a4fe457715e9ac84";
 
 pub fn get_sample_tx_hashes_1() -> Vec<String> {
     vec![
@@ -407,6 +423,48 @@ pub fn get_sample_tx_hashes_3() -> Vec<String> {
     ]
 }
 
+pub fn get_sample_transaction_hashes() -> Vec<String> {
+    // NOTE: One tx of each type, 0 through 4, in that order.
+    vec![
+        "0x33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788".to_string(),
+        "0x2354a60aa22b30effaa1f6bf086d231b3b2294a6f6b2ea24e10bd7f9785f3385".to_string(),
+        "0x5dada4d965a880aa6ae9113b7996aab8c3b426c314cd3a3550f3a810383433fc".to_string(),
+        "0xf5e2c47bd5f388418c6867268cd0e7ce9a28f29fc2b86bea10a59b82126401fe".to_string(),
+        "0xf145688afe2da6f573c7f62bc70f5209c60f8f2a4f2577b970fa4e9db51c26e1".to_string(),
+    ]
+}
+
+pub fn get_sample_transaction_json_path(tx_hash: &str) -> String {
+    format!("{}{}", SAMPLE_TRANSACTION_JSONS_PATH, tx_hash)
+}
+
+pub fn get_sample_transactions() -> Vec<Transaction> {
+    get_sample_transaction_hashes()
+        .iter()
+        .map(|tx_hash| fs::read_to_string(get_sample_transaction_json_path(tx_hash)).unwrap())
+        .map(|rpc_string| deserialize_to_transaction_rpc_response(rpc_string).unwrap())
+        .map(|tx_json| deserialize_transaction_json_to_transaction_struct(tx_json.result).unwrap())
+        .collect::<Vec<Transaction>>()
+}
+
+pub fn get_sample_account_proof() -> AccountProof {
+    fs::read_to_string(SAMPLE_ACCOUNT_PROOF_JSON_PATH)
+        .map(|rpc_string| get_account_proof_from_rpc_response(rpc_string).unwrap())
+        .unwrap()
+}
+
+pub fn get_sample_state_root() -> H256 {
+    convert_hex_to_h256(STATE_ROOT.to_string()).unwrap()
+}
+
+pub fn get_sample_client_block_json_path(client: &str) -> String {
+    format!("{}{}_block_json", SAMPLE_CLIENT_JSONS_PATH, client)
+}
+
+pub fn get_sample_client_receipt_json_path(client: &str) -> String {
+    format!("{}{}_receipt_json", SAMPLE_CLIENT_JSONS_PATH, client)
+}
+
 pub fn get_sample_receipts(path: String, tx_hashes: Vec<String>) -> Vec<Receipt> {
     tx_hashes
         .iter()
@@ -427,6 +485,59 @@ pub fn get_sample_trie_with_sample_receipts(path: String, tx_hashes: Vec<String>
     put_in_trie_recursively(trie, key_value_tuples, index).unwrap()
 }
 
+pub fn get_sample_receipts_1_trie_and_key_value_tuples() -> (Trie, Vec<(Nibbles, Bytes)>) {
+    let receipts = get_sample_receipts(
+        SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+        get_sample_tx_hashes_1(),
+    );
+    let key_value_tuples = get_rlp_encoded_receipts_and_nibble_tuples(&receipts).unwrap();
+    let trie = put_in_trie_recursively(Trie::get_new_trie().unwrap(), key_value_tuples.clone(), 0)
+        .unwrap();
+    (trie, key_value_tuples)
+}
+
+pub fn get_sample_indexed_value(index: usize) -> Bytes {
+    format!("Sample value at index #{:032}", index).into_bytes()
+}
+
+pub fn get_sample_trie_with_indexed_values(num_values: usize) -> Trie {
+    let key_value_tuples = (0..num_values)
+        .map(|index| {
+            (
+                convert_usize_index_to_trie_key(index).unwrap(),
+                get_sample_indexed_value(index),
+            )
+        })
+        .collect();
+    put_in_trie_recursively(Trie::get_new_trie().unwrap(), key_value_tuples, 0).unwrap()
+}
+
+pub fn get_sample_inline_key_value_tuples() -> Vec<(Nibbles, Bytes)> {
+    vec![
+        ("do", "verb"),
+        ("horse", "stallion"),
+        ("doge", "coin"),
+        ("dog", "puppy"),
+    ]
+    .into_iter()
+    .map(|(key, value)| {
+        (
+            get_nibbles_from_bytes(key.as_bytes().to_vec()),
+            value.as_bytes().to_vec(),
+        )
+    })
+    .collect()
+}
+
+pub fn get_sample_trie_with_inline_nodes() -> Trie {
+    put_in_trie_recursively(
+        Trie::get_new_trie().unwrap(),
+        get_sample_inline_key_value_tuples(),
+        0,
+    )
+    .unwrap()
+}
+
 pub fn get_sample_leaf_node() -> Node {
     let path_bytes = vec![0x12, 0x34, 0x56];
     let path_nibbles = get_nibbles_from_bytes(path_bytes.clone());
@@ -457,6 +568,13 @@ pub fn get_sample_branch_node() -> Node {
         .unwrap()
 }
 
+pub fn get_rlp_encoded_nodes_from_node_stack(node_stack: &[Node]) -> Vec<Bytes> {
+    Rlp::new(&rlp_encode_node_stack(node_stack).unwrap())
+        .iter()
+        .map(|node| node.as_raw().to_vec())
+        .collect()
+}
+
 pub fn get_thing_to_put_in_database() -> Bytes {
     "Provable".as_bytes().to_owned()
 }
@@ -509,12 +627,24 @@ pub fn get_valid_state_with_receipts_trie_index_and_branch(
         .and_then(get_branch_from_trie_and_put_in_state)
 }
 
+pub fn get_valid_state_with_transactions_trie() -> Result<State> {
+    get_valid_initial_state()
+        .and_then(|state| state.set_transactions_in_state(get_sample_transactions()))
+        .and_then(get_transactions_trie_and_set_in_state)
+}
+
 pub fn get_expected_block() -> Block {
     let string = fs::read_to_string(SAMPLE_BLOCK_JSON_PATH).unwrap();
     let res = deserialize_to_block_rpc_response(string).unwrap();
     deserialize_block_json_to_block_struct(res.result).unwrap()
 }
 
+pub fn get_sample_prague_block() -> Block {
+    let string = fs::read_to_string(SAMPLE_PRAGUE_BLOCK_JSON_PATH).unwrap();
+    let res = deserialize_to_block_rpc_response(string).unwrap();
+    deserialize_block_json_to_block_struct(res.result).unwrap()
+}
+
 pub fn get_expected_receipt() -> Receipt {
     let string = fs::read_to_string(SAMPLE_RECEIPT_JSON_PATH).unwrap();
     let res = deserialize_to_receipt_rpc_response(string).unwrap();
@@ -545,9 +675,7 @@ pub fn assert_log_is_correct(log: Log) {
     assert!(sample_log.data == log.data);
 }
 
-pub fn assert_block_is_correct(block: Block) {
-    // TODO: Implement == for blocks!
-    let sample_block = get_expected_block();
+fn assert_fields_every_client_returns_are_correct(block: &Block, sample_block: &Block) {
     assert!(block.author == sample_block.author);
     assert!(block.difficulty == sample_block.difficulty);
     assert!(block.extra_data == sample_block.extra_data);
@@ -560,18 +688,34 @@ pub fn assert_block_is_correct(block: Block) {
     assert!(block.number == sample_block.number);
     assert!(block.parent_hash == sample_block.parent_hash);
     assert!(block.receipts_root == sample_block.receipts_root);
-    assert!(block.seal_fields.0 == sample_block.seal_fields.0);
-    assert!(block.seal_fields.1 == sample_block.seal_fields.1);
     assert!(block.sha3_uncles == sample_block.sha3_uncles);
     assert!(block.size == sample_block.size);
     assert!(block.state_root == sample_block.state_root);
     assert!(block.timestamp == sample_block.timestamp);
-    assert!(block.total_difficulty == sample_block.total_difficulty);
     assert!(block.transactions.len() == sample_block.transactions.len());
     assert!(block.transactions_root == sample_block.transactions_root);
     assert!(block.uncles.len() == sample_block.uncles.len());
 }
 
+pub fn assert_block_is_correct(block: Block) {
+    // TODO: Implement == for blocks!
+    let sample_block = get_expected_block();
+    assert_fields_every_client_returns_are_correct(&block, &sample_block);
+    assert!(block.seal_fields == sample_block.seal_fields);
+    assert!(block.total_difficulty == sample_block.total_difficulty);
+}
+
+pub fn assert_client_block_is_correct(block: Block, client: &str) {
+    // NOTE: Only Parity/OpenEthereum returns seal fields, & not every client a total difficulty.
+    let sample_block = get_expected_block();
+    assert_fields_every_client_returns_are_correct(&block, &sample_block);
+    assert!(block.seal_fields.is_none());
+    match SAMPLE_CLIENTS_WITH_TOTAL_DIFFICULTY.contains(&client) {
+        true => assert!(block.total_difficulty == sample_block.total_difficulty),
+        false => assert!(block.total_difficulty.is_none()),
+    }
+}
+
 pub fn convert_hex_string_to_nibbles(hex_string: String) -> Result<Nibbles> {
     match hex_string.len() % 2 == 0 {
         true => Ok(get_nibbles_from_bytes(hex::decode(hex_string)?)),
@@ -587,7 +731,8 @@ pub fn assert_receipt_is_correct(receipt: Receipt) {
     let sample_receipt = get_expected_receipt();
     assert!(receipt.to == sample_receipt.to);
     assert!(receipt.from == sample_receipt.from);
-    assert!(receipt.status == sample_receipt.status);
+    assert!(receipt.outcome == sample_receipt.outcome);
+    assert!(receipt.tx_type == sample_receipt.tx_type);
     assert!(receipt.block_hash == sample_receipt.block_hash);
     assert!(receipt.transaction_hash == sample_receipt.transaction_hash);
     assert!(receipt.cumulative_gas_used == sample_receipt.cumulative_gas_used);
@@ -615,17 +760,30 @@ pub fn restore_env_file(data: String) -> Result<()> {
     Ok(fs::write(&DOT_ENV_PATH, data)?)
 }
 
-pub fn get_database_with_thing_in_it() -> Result<Database> {
-    let mut database: Database = std::collections::HashMap::new();
-    database.insert(
+pub fn get_database_with_thing_in_it() -> Result<MemoryNodeStore> {
+    let mut database = MemoryNodeStore::default();
+    put_thing_in_database(
+        &mut database,
         get_expected_key_of_thing_in_database(),
         "Provable".as_bytes().to_owned(),
-    );
+    )?;
     Ok(database)
 }
 
+pub fn get_temporary_directory(name: &str) -> std::path::PathBuf {
+    let directory = std::env::temp_dir().join(format!(
+        "rusty-receipt-proof-maker-{}-{}",
+        name,
+        std::process::id()
+    ));
+    if directory.exists() {
+        fs::remove_dir_all(&directory).unwrap();
+    };
+    directory
+}
+
 pub fn get_sample_proof_1() -> String {
-    "f91626f871a0fb5e0d429924a0287196102cda8544cfbbb0949d7ae9e6a2ebbdfe4f6e3c94eba0441d343ee56af21fb1a8e12802f9b91f415cb8dd5dcb47f880c4171846e11d69808080808080a0e58215be848c1293dd381210359d84485553000a82b67410406d183b42adbbdd8080808080808080f901f180a0d9673d2d9fc051cd0c137eb6e8e6fa792ca02465188c3408c86625d76f6396a0a068a36b8233852762f709f47f3bf8f49f7617b2768f432c4c53901158466f32cba0a2cb63340b3750a6f8e423d841f0f6ecf1ee905e2c5429215f90abab8cf981c4a0d5400a85346120207d40c40c706329212cad6256d6ac3c98b0a608382b94e49ba0086498ee145780c9a471de9095b478ca1555985afb5af85e9417240c42d1761ca0f919c75704da25ee06c61ec00a09b45e96233400846e210f1556e05e69b4026fa0ff852e16453c79cd5cb47453cc4b813f67ceeec2fba52f8493f34ce4e11f36dfa0e28a542ee13340426eae878242c5f6ae00d32d8428520d0e4d43c941c8501b5ca0690514b2df03293f0a4af4c5c1f7f4e14f597498fcafaef0294f7d9275fafb93a0fe6a59f583d64752de4922fa78290d52214d53dfdb8398ea622458045d1bf790a0b8c0730f4a260ecaff702f95300731b3b0bde28caf3591eb56c376e173655179a0c1fc1ea14cf024f0c58ae16906be6f3db05cee425ebfaded1402b248e979cecaa047b8d8cd77fbba9406f8be39009cd3de8681294ac06321d81ecefcabe2a50f5fa015cabab29394775f3844179f7200ac6f368a89a819ca370b29ef4e01fe1bd5f5a0fbfb53995b5a638a7f32d344fc04d26db19c1e0953d2b3128da43f9343c1e23080f913bc20b913b8f913b50183717d1db90100000000000000000000100000020000042000328000000002000000800000000000000000000100000002200008800000120000880804000040008081002000000000000000000000480004084008000080000404004000400000000880420000000200000220040000000000000809200000000000810400000000100000000000000000401000000000000040020400002000010100000000000000000000000200000000002000000000001040000000000104000000000002200000200110000500028902000000000000000020000000000000020008000800020000200000302000000a2000088002000000000100040000000000400400200040100000f912aaf87a94c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2f842a0e1fffcc4923d04b559f4d29a8bfc6cda04eb5b0d3c460751c2402c5c5cc9109ca0000000000000000000000000d4240987d6f92b06c8b5068b1e4006a97c47392ba00000000000000000000000000000000000000000000000001fa60fb6a27e1b47f89b94c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa0000000000000000000000000d4240987d6f92b06c8b5068b1e4006a97c47392ba00000000000000000000000009b3eb3b22dc2c29e878d7766276a86a8395fb56da00000000000000000000000000000000000000000000000001fa60fb6a27e1b47f89b94c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa00000000000000000000000009b3eb3b22dc2c29e878d7766276a86a8395fb56da00000000000000000000000009ae49c0d7f8f9ef4b864e004fe86ac8294e20950a00000000000000000000000000000000000000000000000001fa60fb6a27e1b47f89b94c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa00000000000000000000000009ae49c0d7f8f9ef4b864e004fe86ac8294e20950a00000000000000000000000009b3eb3b22dc2c29e878d7766276a86a8395fb56da000000000000000000000000000000000000000000000000000be1571569ebfdaf89b94c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa00000000000000000000000009ae49c0d7f8f9ef4b864e004fe86ac8294e20950a000000000000000000000000057f8160e1c59d16c01bbe181fd94db4e56b60495a00000000000000000000000000000000000000000000000001ee7fa454bdf5b6df87a94c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2f842a07fcf532c15f0a6db0bd6d0e038bea71d30d808c7d98cb3bf7268a95bf5081b65a000000000000000000000000057f8160e1c59d16c01bbe181fd94db4e56b60495a00000000000000000000000000000000000000000000000001ee7fa454bdf5b6df87a949ae49c0d7f8f9ef4b864e004fe86ac8294e20950f842a075f33ed68675112c77094e7c5b073890598be1d23e27cd7f6907b4a7d98ac619a000000000000000000000000057f8160e1c59d16c01bbe181fd94db4e56b60495a00000000000000000000000000000000000000000000000001ee7fa454bdf5b6df8fb9457f8160e1c59d16c01bbe181fd94db4e56b60495f842a0ea9415385bae08fe9f6dc457b02577166790cde83bb18cc340aac6cb81b824dea00000000000000000000000009ae49c0d7f8f9ef4b864e004fe86ac8294e20950b8a0000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc20000000000000000000000000000000000000000000000001ee7fa454bdf5b6d000000000000000000000000eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee0000000000000000000000000000000000000000000000001ee7fa454bdf5b6d0000000000000000000000009ae49c0d7f8f9ef4b864e004fe86ac8294e20950f89b94a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000063825c174ab367968ec60f061753d3bbd36a0d8fa00000000000000000000000009ae49c0d7f8f9ef4b864e004fe86ac8294e20950a000000000000000000000000000000000000000000000000000000000172b812cf8fb9463825c174ab367968ec60f061753d3bbd36a0d8ff842a0ea9415385bae08fe9f6dc457b02577166790cde83bb18cc340aac6cb81b824dea00000000000000000000000009ae49c0d7f8f9ef4b864e004fe86ac8294e20950b8a0000000000000000000000000eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee0000000000000000000000000000000000000000000000001ee7fa454bdf5b6d000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb4800000000000000000000000000000000000000000000000000000000172b812c0000000000000000000000009ae49c0d7f8f9ef4b864e004fe86ac8294e20950f89b94a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa00000000000000000000000009ae49c0d7f8f9ef4b864e004fe86ac8294e20950a00000000000000000000000009b3eb3b22dc2c29e878d7766276a86a8395fb56da000000000000000000000000000000000000000000000000000000000172b812cf8799452166528fcc12681af996e409ee3a421a4e128a3e1a0f838f6ddc89706878e3c3e698e9b5cbfbf2c0e3d3dcd0bd2e00f1ccf313e0185b84000000000000000000000000063825c174ab367968ec60f061753d3bbd36a0d8f0000000000000000000000000000000000000000000000006261b3899e23cbe6f9019c949ae49c0d7f8f9ef4b864e004fe86ac8294e20950f842a0d30ca399cb43507ecec6a629a35cf45eb98cda550c27696dcb0d8c4a3873ce6ca00000000000000000000000009b3eb3b22dc2c29e878d7766276a86a8395fb56db90140000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb480000000000000000000000000000000000000000000000001ee7fa454bdf5b6d00000000000000000000000000000000000000000000000000000000172b812c0000000000000000000000009b3eb3b22dc2c29e878d7766276a86a8395fb56d0000000000000000000000000000000000000000000000001ee7fa454bdf5b6d00000000000000000000000057f8160e1c59d16c01bbe181fd94db4e56b6049500000000000000000000000063825c174ab367968ec60f061753d3bbd36a0d8f00000000000000000000000000000000000000000000000000000000000001200000000000000000000000000000000000000000000000000000000000000000f8db94818e6fecd516ecc3849daf6845e3ec868087b755f842a01849bd6a030a1bca28b83437fd3de96f3d27a5d172fa7e9c78e7b61468928a39a00000000000000000000000009b3eb3b22dc2c29e878d7766276a86a8395fb56db880000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb480000000000000000000000000000000000000000000000001ee7fa454bdf5b6d00000000000000000000000000000000000000000000000000000000172b812cf89b94c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa00000000000000000000000009b3eb3b22dc2c29e878d7766276a86a8395fb56da0000000000000000000000000d4240987d6f92b06c8b5068b1e4006a97c47392ba000000000000000000000000000000000000000000000000000be1571569ebfdaf89b94a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa00000000000000000000000009b3eb3b22dc2c29e878d7766276a86a8395fb56da00000000000000000000000005b67871c3a857de81a1ca0f9f7945e5670d986dca000000000000000000000000000000000000000000000000000000000172b812cf89b94d14d4e7eb9b36ae1ac0efd5e0833bf517eafd91cf863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa00000000000000000000000000000000000000000000000000000000000000000a0000000000000000000000000d4240987d6f92b06c8b5068b1e4006a97c47392ba000000000000000000000000000000000000000000000000011927d1af6564000f87994f55186cc537e7067ea616f2aae007b4427a120c8e1a09c2c6ec1cb8ee2fe8d5549d7d071a1a8f76ec3cc057d7c46f118247b0e5e8572b840000000000000000000000000d14d4e7eb9b36ae1ac0efd5e0833bf517eafd91c00000000000000000000000000000000000000000000000011927d1af6564000f9015c9473df03b5436c84cf9d5a758fb756928dceaf19d7f842a0c7fce5271a7dcbf20bd48128dcbf6f2df01bceda67919e43870de3be7f1b0690a0000000000000000000000000d4240987d6f92b06c8b5068b1e4006a97c47392bb90100000000000000000000000000d14d4e7eb9b36ae1ac0efd5e0833bf517eafd91c00000000000000000000000000000000000000000000000011927d1af6564000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000000001000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc200000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000001fa60fb6a27e1b47f89b94d14d4e7eb9b36ae1ac0efd5e0833bf517eafd91cf863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa0000000000000000000000000d4240987d6f92b06c8b5068b1e4006a97c47392ba00000000000000000000000005b67871c3a857de81a1ca0f9f7945e5670d986dca000000000000000000000000000000000000000000000000011927d1af6541593f89b94d14d4e7eb9b36ae1ac0efd5e0833bf517eafd91cf863a08c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925a0000000000000000000000000d4240987d6f92b06c8b5068b1e4006a97c47392ba0000000000000000000000000882d80d3a191859d64477eb78cca46599307ec1ca0fffffffffffffffffffffffffffffffffffffffffffffff67d19c841ccf2e46cf89b949ea463ec4ce9e9e5bc9cfd0187c4ac3a70dd951df863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000005be139fa43fdc0e583ac0e4fab48e5e451fa6575a00000000000000000000000000000000000000000000000001460fce85296abc0f87994f55186cc537e7067ea616f2aae007b4427a120c8e1a09c2c6ec1cb8ee2fe8d5549d7d071a1a8f76ec3cc057d7c46f118247b0e5e8572b8400000000000000000000000009ea463ec4ce9e9e5bc9cfd0187c4ac3a70dd951d0000000000000000000000000000000000000000000000001460fce85296abc0f89b94d14d4e7eb9b36ae1ac0efd5e0833bf517eafd91cf863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa0000000000000000000000000d4240987d6f92b06c8b5068b1e4006a97c47392ba00000000000000000000000005b67871c3a857de81a1ca0f9f7945e5670d986dca00000000000000000000000000000000000000000000000000000000000022a6df89b94d14d4e7eb9b36ae1ac0efd5e0833bf517eafd91cf863a08c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925a0000000000000000000000000d4240987d6f92b06c8b5068b1e4006a97c47392ba0000000000000000000000000882d80d3a191859d64477eb78cca46599307ec1ca0fffffffffffffffffffffffffffffffffffffffffffffff67d19c841ccf0b9fff87a94c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2f842a07fcf532c15f0a6db0bd6d0e038bea71d30d808c7d98cb3bf7268a95bf5081b65a0000000000000000000000000d4240987d6f92b06c8b5068b1e4006a97c47392ba000000000000000000000000000000000000000000000000000be1571569ebfdaf8dc94d4240987d6f92b06c8b5068b1e4006a97c47392bf863a000293d5012632fad25e327fa894460c60bef74241d2f04c42802f4b2212f66aaa00000000000000000000000009ea463ec4ce9e9e5bc9cfd0187c4ac3a70dd951da00000000000000000000000005be139fa43fdc0e583ac0e4fab48e5e451fa6575b860000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc20000000000000000000000000000000000000000000000001460fce85296abc000000000000000000000000000000000000000000000000000be1571569ebfda".to_string()
+    fs::read_to_string(SAMPLE_PROOF_1_PATH).unwrap()
 }
 
 pub fn get_sample_proof_3() -> String {
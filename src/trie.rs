@@ -1,92 +1,192 @@
 use crate::constants::{EMPTY_NIBBLES, HASHED_NULL_NODE};
 use crate::errors::AppError;
 use crate::get_database::{get_new_database, put_thing_in_database, remove_thing_from_database};
+use crate::get_keccak_hash::keccak_hash_bytes;
 use crate::nibble_utils::{
-    convert_nibble_to_usize, get_common_prefix_nibbles, get_nibble_at_index,
-    get_nibbles_from_bytes, split_at_first_nibble, Nibbles,
+    concatenate_nibbles, convert_nibble_to_usize, get_common_prefix_nibbles, get_nibble_at_index,
+    get_nibbles_from_bytes, get_nibbles_from_offset_bytes, split_at_first_nibble, Nibbles,
 };
-use crate::trie_nodes::{get_node_from_database, Node};
-use crate::types::{Bytes, Database, NodeStack, NoneError, Result};
+use crate::node_store::{MemoryNodeStore, NodeStore};
+use crate::trie_nodes::{
+    get_existing_node_from_reference, get_node_from_database, is_inline_node_reference,
+    rlp_decode_node, BranchNode, Node,
+};
+use crate::types::{Byte, Bytes, Database, NodeStack, NoneError, Result};
 use crate::utils::{convert_bytes_to_h256, convert_h256_to_bytes};
 use ethereum_types::H256;
 
 #[derive(Clone)]
-pub struct Trie {
+pub struct Trie<D: NodeStore = MemoryNodeStore> {
     pub root: H256,
-    pub database: Database,
+    pub database: D,
+    pub secure: bool,
 }
 
 impl Trie {
     pub fn get_new_trie() -> Result<Trie> {
+        Trie::get_new_trie_in_store(get_new_database()?)
+    }
+    /**
+     * A secure trie, as used for Ethereum's state & storage tries, stores each
+     * value under the keccak hash of its key rather than under the key itself.
+     * Keys passed to `put` & `find` are hashed before the trie is walked, so
+     * they must be whole bytes.
+     *
+     */
+    pub fn get_new_secure_trie() -> Result<Trie> {
+        Trie::get_new_trie().map(|trie| Trie {
+            secure: true,
+            ..trie
+        })
+    }
+    /**
+     *
+     * Getting a Partial Trie from Proof Nodes:
+     *
+     * Seeds a trie with only the rlp encoded nodes of a proof, such as those
+     * from `decode_hex_proof_to_rlp_encoded_nodes`, opened at the root they
+     * prove against. Keys along the paths the proof covers can then be got,
+     * found & updated as in a full trie, while any walk needing a node the
+     * proof lacks fails with an `AppError::MissingNodeError`.
+     *
+     * Each node is put once per reference to it, from the root & from the
+     * proof's other nodes, so that its reference count matches its uses. A
+     * node only ever referred to from outside the proof can't be reached, so
+     * isn't counted, and any node nothing refers to is left out entirely.
+     *
+     */
+    pub fn get_partial_trie_from_proof_nodes(
+        root: H256,
+        rlp_encoded_nodes: &[Bytes],
+    ) -> Result<Trie> {
+        get_database_from_proof_nodes(root, rlp_encoded_nodes)
+            .and_then(|database| Trie::open_trie_at_root(root, database))
+    }
+    /**
+     * As `get_partial_trie_from_proof_nodes`, but for proofs from a secure
+     * trie, such as those of `eth_getProof`, so that keys are hashed before
+     * the partial trie is walked.
+     *
+     */
+    pub fn get_partial_secure_trie_from_proof_nodes(
+        root: H256,
+        rlp_encoded_nodes: &[Bytes],
+    ) -> Result<Trie> {
+        get_database_from_proof_nodes(root, rlp_encoded_nodes)
+            .and_then(|database| Trie::open_secure_trie_at_root(root, database))
+    }
+}
+
+fn get_database_from_proof_nodes(
+    root: H256,
+    rlp_encoded_nodes: &[Bytes],
+) -> Result<MemoryNodeStore> {
+    let nodes_by_hash = rlp_encoded_nodes
+        .iter()
+        .map(|node| Ok((keccak_hash_bytes(node)?, node.clone())))
+        .collect::<Result<Database>>()?;
+    let child_hashes = nodes_by_hash
+        .values()
+        .map(|node| rlp_decode_node(node.clone()).map(|node| node.get_child_references()))
+        .collect::<Result<Vec<Vec<Bytes>>>>()?
+        .concat()
+        .iter()
+        .filter(|reference| !is_inline_node_reference(reference))
+        .map(|reference| convert_bytes_to_h256(reference))
+        .collect::<Result<Vec<H256>>>()?;
+    let mut database = get_new_database()?;
+    std::iter::once(root)
+        .chain(child_hashes)
+        .filter_map(|hash| nodes_by_hash.get(&hash).map(|node| (hash, node.clone())))
+        .try_for_each(|(hash, node)| put_thing_in_database(&mut database, hash, node))?;
+    Ok(database)
+}
+
+impl<D: NodeStore> Trie<D> {
+    pub fn get_new_trie_in_store(database: D) -> Result<Trie<D>> {
         Ok(Trie {
             root: HASHED_NULL_NODE,
-            database: get_new_database()?,
+            database,
+            secure: false,
         })
     }
+    /**
+     * Reopens a trie previously built in the given store, such as a
+     * `FileNodeStore`, at its known root hash without rebuilding it. Only the
+     * root node is checked for here, with the rest fetched as the trie is
+     * walked. A secure trie is reopened via `open_secure_trie_at_root`.
+     *
+     */
+    pub fn open_trie_at_root(root: H256, database: D) -> Result<Trie<D>> {
+        match root == HASHED_NULL_NODE || database.contains(&root)? {
+            true => Ok(Trie {
+                root,
+                database,
+                secure: false,
+            }),
+            false => Err(AppError::MissingNodeError(root)),
+        }
+    }
 
-    pub fn put(self, key: Nibbles, value: Bytes) -> Result<Self> {
-        trace!("Putting new value in trie under path: {:?}", key);
-        match self.root == HASHED_NULL_NODE {
-            true => {
-                trace!("Trie empty ∴ creating new leaf node...");
-                Node::get_new_leaf_node(key, value)
-                    .and_then(|leaf| self.update_trie_database(vec![leaf], Vec::new()))
-            }
-            false => {
-                trace!("Trie not-empty ∴ finding nearest node to key...");
-                Trie::find(self, key)
-                    .and_then(|(self_, target_key, found_stack, remaining_key)| {
-                        self_.process_found_node_stack(
-                            target_key,
-                            found_stack,
-                            remaining_key,
-                            value,
-                        )
-                    })
-                    .and_then(
-                        |(self_, target_key, old_stack, new_stack, stack_to_delete)| {
-                            self_.update_stale_nodes(
-                                target_key,
-                                old_stack,
-                                new_stack,
-                                stack_to_delete,
-                            )
-                        },
-                    )
-                    .and_then(|(self_, _, _, new_stack, stack_to_delete)| {
-                        self_.update_trie_database(new_stack, stack_to_delete)
-                    })
-            }
+    pub fn open_secure_trie_at_root(root: H256, database: D) -> Result<Trie<D>> {
+        Trie::open_trie_at_root(root, database).map(|trie| Trie {
+            secure: true,
+            ..trie
+        })
+    }
+
+    fn get_path_from_key(&self, key: Nibbles) -> Result<Nibbles> {
+        match (self.secure, key.offset) {
+            (false, _) => Ok(key),
+            (true, 0) => get_secure_trie_key(&key.data),
+            (true, _) => Err(AppError::Custom(
+                "✘ Secure trie keys must be a whole number of bytes!".to_string(),
+            )),
         }
     }
 
+    pub fn put(&mut self, key: Nibbles, value: Bytes) -> Result<()> {
+        let key = self.get_path_from_key(key)?;
+        trace!("Putting new value in trie under path: {:?}", key);
+        self.find_from_root(key)
+            .and_then(|(target_key, found_stack, remaining_key)| {
+                self.process_found_node_stack(target_key, found_stack, remaining_key, value)
+            })
+            .and_then(|(target_key, old_stack, new_stack, stack_to_delete)| {
+                self.update_stale_nodes(target_key, old_stack, new_stack, stack_to_delete)
+            })
+            .and_then(|(_, _, new_stack, stack_to_delete)| {
+                self.update_trie_database(new_stack, stack_to_delete)
+            })
+    }
+
     fn process_found_node_stack(
-        self,
+        &self,
         target_key: Nibbles,
         mut found_stack: NodeStack,
         remaining_key: Nibbles,
         value: Bytes,
-    ) -> Result<(Self, Nibbles, NodeStack, NodeStack, NodeStack)> {
+    ) -> Result<(Nibbles, NodeStack, NodeStack, NodeStack)> {
         match found_stack.pop() {
-            Some(node) => match node.get_type() {
-                "leaf" => {
+            Some(node) => match node {
+                Node::Null => self.process_from_null_node(target_key, found_stack, value),
+                Node::Leaf(_) => {
                     self.process_from_leaf_node(target_key, node, found_stack, remaining_key, value)
                 }
-                "branch" => self.process_from_branch_node(
+                Node::Branch(_) => self.process_from_branch_node(
                     target_key,
                     node,
                     found_stack,
                     remaining_key,
                     value,
                 ),
-                "extension" => self.process_from_extension_node(
+                Node::Extension(_) => self.process_from_extension_node(
                     target_key,
                     node,
                     found_stack,
                     remaining_key,
                     value,
                 ),
-                _ => Err(AppError::Custom("✘ Node type not recognized!".to_string())),
             },
             None => Err(AppError::Custom(
                 "✘ Cannot process node stack: It's empty!".to_string(),
@@ -95,337 +195,186 @@ impl Trie {
     }
     /**
      *
-     * Processing from an extension node has the following two cases:
-     *
-     * 1) No common-prefix between extension node's path and remaining key.
-     * 2) A common-prefix between extension node's path and remaining key.
-     *
-     * In the first case we split the nodes at the first nibbles and create a
-     * branch to point at the original extension - which is first updated with
-     * a path one nibble shorter - and a leaf which is created from the
-     * remaining key minus its first nibble and the value to be stored.
-     *
-     * In the second, much the same as above occurs except the split happens
-     * somewhere other than the first nibble. In this case, a new extension is
-     * created to consume the common prefix, which then points at the branch
-     * and it's children per case 1).
-     *
-     * The first case also has a sub-case for when the extension node key is
-     * exactly two nibbles long. In this case, no new extension node is created.
-     * Instead, the new branch that is created gets the new leaf's hash in it
-     * as usual, as well as the hash that was the value of the original
-     * extension node, in the position governed by the last nibble of the
-     * two-nibble-long extension node. The extension node now fully depleted
-     * is condemned to the delete stack.
-     *
-     * FIXME: TODO:
-     * Case two has a similar sub-case as above, when the common-prefix consumes
-     * all but the LAST nibble of the extension. Here again we don't need a new
-     * extension creating, because the new branch will inherit the hash the
-     * extension was pointing to in the position governed by the last nibble of
-     * the extension. The original extension is thus shortened by one nibble
-     * from the end of the key, and it's pointer updated to point at the new
-     * branch we've created.
+     * Processing from the null node:
+     *
+     * The null node is only ever found as the root of an empty trie, and so
+     * the whole of the key remains. A new leaf holding the value under it
+     * becomes the trie's sole node, with nothing stale to delete since the
+     * null node is never saved.
+     *
+     */
+    fn process_from_null_node(
+        &self,
+        target_key: Nibbles,
+        found_stack: NodeStack,
+        value: Bytes,
+    ) -> Result<(Nibbles, NodeStack, NodeStack, NodeStack)> {
+        trace!("Trie empty ∴ creating new leaf node...");
+        Node::get_new_leaf_node(target_key.clone(), value)
+            .map(|leaf| (target_key, found_stack, vec![leaf], Vec::new()))
+    }
+    /**
+     *
+     * Processing from an extension node:
+     *
+     * Here the remaining key diverges from the extension's path somewhere
+     * before its end, else finding would have continued past it. The split is
+     * made at the point of divergence, per `get_nodes_from_split`, and the
+     * original extension is condemned to the delete stack.
      *
      */
     fn process_from_extension_node(
-        self,
+        &self,
         target_key: Nibbles,
         current_ext_node: Node,
         found_stack: NodeStack,
         remaining_key: Nibbles,
         value: Bytes,
-    ) -> Result<(Self, Nibbles, NodeStack, NodeStack, NodeStack)> {
+    ) -> Result<(Nibbles, NodeStack, NodeStack, NodeStack)> {
         trace!("Processing from extension node...");
-        let mut new_stack: NodeStack = Vec::new();
-        get_common_prefix_nibbles(remaining_key, current_ext_node.get_key()).and_then(
-            |(common_prefix, key_remainder, node_key_remainder)| {
-                trace!(
-                    "Extension node key remaining length: {}",
-                    node_key_remainder.len()
-                );
-                match common_prefix.len() {
-                    0 => match node_key_remainder.len() {
-                        1 => split_at_first_nibble(&node_key_remainder).and_then(
-                            |(ext_first_nibble, _)| {
-                                trace!(
-                                    "No common prefix ∴ transmuting existing {}",
-                                    "ext to: branch -> leaf"
-                                );
-                                let (key_remainder_first_nibble, key_remainder_nibbles) =
-                                    split_at_first_nibble(&key_remainder)?;
-                                let new_leaf =
-                                    Node::get_new_leaf_node(key_remainder_nibbles, value)?;
-                                let branch = Node::get_new_branch_node(None)?;
-                                let updated_branch_1 = branch.update_branch_at_index(
-                                    Some(current_ext_node.get_value().ok_or_else(|| {
-                                        NoneError("Could not get extension node value!".into())
-                                    })?),
-                                    convert_nibble_to_usize(ext_first_nibble),
-                                )?;
-                                let new_branch = updated_branch_1.update_branch_at_index(
-                                    Some(convert_h256_to_bytes(new_leaf.get_hash()?)),
-                                    convert_nibble_to_usize(key_remainder_first_nibble),
-                                )?;
-                                new_stack.push(new_branch);
-                                new_stack.push(new_leaf);
-                                let stack_to_delete = vec![current_ext_node];
-                                Ok((self, target_key, found_stack, new_stack, stack_to_delete))
-                            },
-                        ),
-                        _ => split_at_first_nibble(&node_key_remainder).and_then(
-                            |(ext_first_nibble, ext_nibbles)| {
-                                trace!(
-                                    "No common prefix ∴ transmuting existing {}",
-                                    "ext to: branch -> ext & leaf"
-                                );
-                                let (key_remainder_first_nibble, key_remainder_nibbles) =
-                                    split_at_first_nibble(&key_remainder)?;
-                                let new_leaf =
-                                    Node::get_new_leaf_node(key_remainder_nibbles, value)?;
-                                let new_ext = Node::get_new_extension_node(
-                                    ext_nibbles,
-                                    current_ext_node.get_value().ok_or_else(|| {
-                                        NoneError("Could not get extension node value!".into())
-                                    })?,
-                                )?;
-                                let branch = Node::get_new_branch_node(None)?;
-                                let updated_branch_1 = branch.update_branch_at_index(
-                                    Some(convert_h256_to_bytes(new_ext.get_hash()?)),
-                                    convert_nibble_to_usize(ext_first_nibble),
-                                )?;
-                                let new_branch = updated_branch_1.update_branch_at_index(
-                                    Some(convert_h256_to_bytes(new_leaf.get_hash()?)),
-                                    convert_nibble_to_usize(key_remainder_first_nibble),
-                                )?;
-                                new_stack.push(new_branch);
-                                new_stack.push(new_ext);
-                                new_stack.push(new_leaf);
-                                Ok((self, target_key, found_stack, new_stack, Vec::new()))
-                            },
-                        ),
-                    },
-                    _ => {
-                        trace!(
-                            "Common prefix ∴ transmuting existing ext to: {}",
-                            "ext -> branch -> ext & leaf"
-                        );
-                        let (key_remainder_first_nibble, key_remainder_nibbles) =
-                            split_at_first_nibble(&key_remainder)?;
-                        let (node_key_remainder_first_nibble, node_key_remainder_nibbles) =
-                            split_at_first_nibble(&node_key_remainder)?;
-                        let ext_below_branch = Node::get_new_extension_node(
-                            node_key_remainder_nibbles,
-                            current_ext_node.get_value().ok_or_else(|| {
-                                NoneError("Could not get extension node value!".into())
-                            })?,
-                        )?;
-                        let new_leaf = Node::get_new_leaf_node(key_remainder_nibbles, value)?;
-                        let empty_branch = Node::get_new_branch_node(None)?;
-                        let updated_branch = empty_branch.update_branch_at_index(
-                            Some(convert_h256_to_bytes(new_leaf.get_hash()?)),
-                            convert_nibble_to_usize(key_remainder_first_nibble),
-                        )?;
-                        let final_branch = updated_branch.update_branch_at_index(
-                            Some(convert_h256_to_bytes(ext_below_branch.get_hash()?)),
-                            convert_nibble_to_usize(node_key_remainder_first_nibble),
-                        )?;
-                        let final_branch_hash = convert_h256_to_bytes(final_branch.get_hash()?);
-                        let ext_above_branch =
-                            Node::get_new_extension_node(common_prefix, final_branch_hash)?;
-                        new_stack.push(new_leaf);
-                        new_stack.push(ext_below_branch);
-                        new_stack.push(final_branch);
-                        new_stack.push(ext_above_branch);
-                        Ok((self, target_key, found_stack, new_stack, Vec::new()))
-                    }
-                }
-            },
-        )
+        get_common_prefix_nibbles(remaining_key, current_ext_node.get_key())
+            .and_then(|(common_prefix, key_remainder, node_key_remainder)| {
+                get_nodes_from_split(
+                    &current_ext_node,
+                    common_prefix,
+                    key_remainder,
+                    node_key_remainder,
+                    value,
+                )
+            })
+            .map(|new_stack| {
+                let stack_to_delete = vec![current_ext_node];
+                (target_key, found_stack, new_stack, stack_to_delete)
+            })
     }
     /**
      *
      * Processing from a leaf node considers the following cases:
      *
-     * 1) No remaining target key.
-     * 2) Some remaining key w/ a common prefix between it and the found leaf.
-     * 3) Some remaining key w/ no common prefix between it and the found leaf.
+     * 1) No remaining target key, the leaf's path having consumed its end.
+     * 2) Some remaining key, or a key which ended before the leaf's path did.
      *
      * The first case is a full match, and so we simply update the value found
      * in the leaf node to the new value provided.
      *
-     * The second case is a partial key match which requires a split at the
-     * first nibble of the remaining key, creating a branch. The existing leaf's
-     * path is then shortened by a single nibble, and a second leaf is created
-     * from the remaining key (minus its first nibble) and the final value.
-     * The hashes of those leaves are then updated in the previously created
-     * branch.
-     *
-     * The third case is also a partial match, but which requires a split
-     * somewhere other than the first nibble of the remaining key. This results
-     * in the same branch & two new leaves (the original one w/ a now shorter
-     * path) as in case two, as well as an extension node consuming the common
-     * prefix and pointing to the branch at which the divergence occurs...
+     * The second case is a partial match, and so the leaf is split at the
+     * point where the remaining key diverges from its path, per
+     * `get_nodes_from_split`. The original leaf is condemned to the delete
+     * stack.
      *
      */
     fn process_from_leaf_node(
-        self,
+        &self,
         target_key: Nibbles,
         current_leaf_node: Node,
         found_stack: NodeStack,
         remaining_key: Nibbles,
         value: Bytes,
-    ) -> Result<(Self, Nibbles, NodeStack, NodeStack, NodeStack)> {
+    ) -> Result<(Nibbles, NodeStack, NodeStack, NodeStack)> {
         trace!("Processing from leaf node...");
         let mut new_stack: NodeStack = Vec::new();
-        match remaining_key.len() {
-            0 => Node::get_new_leaf_node(current_leaf_node.get_key(), value).map(|new_leaf| {
+        match is_full_match(
+            &target_key,
+            &found_stack,
+            &current_leaf_node,
+            &remaining_key,
+        ) {
+            true => Node::get_new_leaf_node(current_leaf_node.get_key(), value).map(|new_leaf| {
                 trace!("No key remaining ∴ creating new leaf node");
                 new_stack.push(new_leaf);
-                (self, target_key, found_stack, new_stack, Vec::new())
+                let stack_to_delete = vec![current_leaf_node];
+                (target_key, found_stack, new_stack, stack_to_delete)
             }),
-            _ => {
-                get_common_prefix_nibbles(remaining_key.clone(), current_leaf_node.get_key()) // FIXME: rm clones
-                    .and_then(|(common_prefix, key_remainder, node_key_remainder)| {
-                        match common_prefix.len() {
-                            0 => split_at_first_nibble(&node_key_remainder).and_then(
-                                |(leaf_first_nibble, leaf_nibbles)| {
-                                    trace!("No common prefix ∴ creating: {}", "branch -> 2 leaves");
-                                    let (key_remainder_first_nibble, key_remainder_nibbles) =
-                                        split_at_first_nibble(&key_remainder)?;
-                                    let new_leaf_1 = Node::get_new_leaf_node(
-                                        leaf_nibbles,
-                                        current_leaf_node.get_value().ok_or_else(|| {
-                                            NoneError("Could not get lead node value!".into())
-                                        })?,
-                                    )?;
-                                    let new_leaf_2 =
-                                        Node::get_new_leaf_node(key_remainder_nibbles, value)?;
-                                    let new_branch = Node::get_new_branch_node(None)?;
-                                    let updated_branch_1 = new_branch.update_branch_at_index(
-                                        Some(convert_h256_to_bytes(new_leaf_1.get_hash()?)),
-                                        convert_nibble_to_usize(leaf_first_nibble),
-                                    )?;
-                                    let updated_branch = updated_branch_1.update_branch_at_index(
-                                        Some(convert_h256_to_bytes(new_leaf_2.get_hash()?)),
-                                        convert_nibble_to_usize(key_remainder_first_nibble),
-                                    )?;
-                                    new_stack.push(updated_branch);
-                                    new_stack.push(new_leaf_1);
-                                    new_stack.push(new_leaf_2);
-                                    Ok((self, target_key, found_stack, new_stack, Vec::new()))
-                                },
-                            ),
-                            _ => split_at_first_nibble(&node_key_remainder).and_then(
-                                |(leaf_first_nibble, leaf_nibbles)| {
-                                    trace!(
-                                        "Common prefix ∴ creating: ext -> branch{}",
-                                        " -> 2 leaves"
-                                    );
-                                    trace!("CP = {:?}", common_prefix);
-                                    trace!(
-                                        "Between keys {:?} & {:?}",
-                                        remaining_key,
-                                        current_leaf_node.get_key()
-                                    );
-                                    let (key_remainder_first_nibble, key_remainder_nibbles) =
-                                        split_at_first_nibble(&key_remainder)?;
-                                    let new_leaf_1 = Node::get_new_leaf_node(
-                                        leaf_nibbles,
-                                        current_leaf_node.get_value().ok_or_else(|| {
-                                            NoneError("Could not get leaf node value!".into())
-                                        })?,
-                                    )?;
-                                    let new_leaf_2 =
-                                        Node::get_new_leaf_node(key_remainder_nibbles, value)?;
-                                    let new_branch = Node::get_new_branch_node(None)?;
-                                    let updated_branch_1 = new_branch.update_branch_at_index(
-                                        Some(convert_h256_to_bytes(new_leaf_1.get_hash()?)),
-                                        convert_nibble_to_usize(leaf_first_nibble),
-                                    )?;
-                                    let updated_branch = updated_branch_1.update_branch_at_index(
-                                        Some(convert_h256_to_bytes(new_leaf_2.get_hash()?)),
-                                        convert_nibble_to_usize(key_remainder_first_nibble),
-                                    )?;
-                                    let updated_branch_hash =
-                                        convert_h256_to_bytes(updated_branch.get_hash()?);
-                                    let new_extension = Node::get_new_extension_node(
-                                        common_prefix,
-                                        updated_branch_hash,
-                                    )?;
-                                    new_stack.push(new_extension);
-                                    new_stack.push(updated_branch);
-                                    new_stack.push(new_leaf_1);
-                                    new_stack.push(new_leaf_2);
-                                    Ok((self, target_key, found_stack, new_stack, Vec::new()))
-                                },
-                            ),
-                        }
-                    })
-            }
+            false => get_common_prefix_nibbles(remaining_key, current_leaf_node.get_key())
+                .and_then(|(common_prefix, key_remainder, node_key_remainder)| {
+                    get_nodes_from_split(
+                        &current_leaf_node,
+                        common_prefix,
+                        key_remainder,
+                        node_key_remainder,
+                        value,
+                    )
+                })
+                .map(|new_stack| {
+                    let stack_to_delete = vec![current_leaf_node];
+                    (target_key, found_stack, new_stack, stack_to_delete)
+                }),
         }
     }
     /**
      * Processing from Branch Node:
      *
-     * Here we create a new leaf node from the remaining key minus its first
-     * nibble. Next we get that node's hash and add it to the current branch
-     * node, at the index the first nibble we chopped off the remaining key
-     * points to.
+     * If no key remains, the value belongs in the branch node itself, and so
+     * the branch's value is updated to it.
+     *
+     * Otherwise we create a new leaf node from the remaining key minus its
+     * first nibble. Next we get that node's hash and add it to the current
+     * branch node, at the index the first nibble we chopped off the remaining
+     * key points to.
      *
      */
     fn process_from_branch_node(
-        self,
+        &self,
         target_key: Nibbles,
         current_branch_node: Node,
         found_stack: NodeStack,
         remaining_key: Nibbles,
         value: Bytes,
-    ) -> Result<(Self, Nibbles, NodeStack, NodeStack, NodeStack)> {
+    ) -> Result<(Nibbles, NodeStack, NodeStack, NodeStack)> {
         trace!("Processing from branch node...");
+        let stack_to_delete = vec![current_branch_node.clone()];
+        if remaining_key.len() == 0 {
+            trace!("No key remaining ∴ updating branch node's value...");
+            return current_branch_node
+                .update_branch_value(Some(value))
+                .map(|updated_branch| {
+                    let new_stack = vec![updated_branch];
+                    (target_key, found_stack, new_stack, stack_to_delete)
+                });
+        };
         split_at_first_nibble(&remaining_key)
             .and_then(|(first_nibble, remaining_nibbles)| {
                 trace!("Creating new leaf & updating branch node...");
                 let new_leaf = Node::get_new_leaf_node(remaining_nibbles, value)?;
-                let new_leaf_hash = convert_h256_to_bytes(new_leaf.get_hash()?);
+                let new_leaf_reference = new_leaf.get_reference()?;
                 let updated_branch = current_branch_node.update_branch_at_index(
-                    Some(new_leaf_hash),
+                    Some(new_leaf_reference),
                     convert_nibble_to_usize(first_nibble),
                 )?;
                 let new_stack: NodeStack = vec![updated_branch, new_leaf];
                 Ok(new_stack)
             })
-            .map(|new_stack| (self, target_key, found_stack, new_stack, Vec::new()))
+            .map(|new_stack| (target_key, found_stack, new_stack, stack_to_delete))
     }
 
     fn update_stale_nodes(
-        self,
+        &self,
         target_key: Nibbles,
         mut old_stack: NodeStack,
         new_stack: NodeStack,
         stack_to_delete: NodeStack,
-    ) -> Result<(Self, Nibbles, NodeStack, NodeStack, NodeStack)> {
+    ) -> Result<(Nibbles, NodeStack, NodeStack, NodeStack)> {
         match old_stack.pop() {
-            Some(current_node) => match current_node.get_type() {
-                "branch" => self.update_nodes_from_old_branch_node(
+            Some(current_node) => match current_node {
+                Node::Branch(_) => self.update_nodes_from_old_branch_node(
                     target_key,
                     current_node,
                     old_stack,
                     new_stack,
                     stack_to_delete,
                 ),
-                "extension" => self.update_nodes_from_old_extension_node(
+                Node::Extension(_) => self.update_nodes_from_old_extension_node(
                     target_key,
                     current_node,
                     old_stack,
                     new_stack,
                     stack_to_delete,
                 ),
-                _ => Err(AppError::Custom(
+                Node::Null | Node::Leaf(_) => Err(AppError::Custom(
                     "✘ Error updating old nodes: Wrong node type!".to_string(),
                 )),
             },
-            None => Ok((self, target_key, old_stack, new_stack, stack_to_delete)),
+            None => Ok((target_key, old_stack, new_stack, stack_to_delete)),
         }
     }
     /**
@@ -439,19 +388,16 @@ impl Trie {
      *
      */
     fn update_nodes_from_old_extension_node(
-        self,
+        &self,
         target_key: Nibbles,
         current_node: Node,
         old_stack: NodeStack,
         mut new_stack: NodeStack,
         mut stack_to_delete: NodeStack,
-    ) -> Result<(Self, Nibbles, NodeStack, NodeStack, NodeStack)> {
+    ) -> Result<(Nibbles, NodeStack, NodeStack, NodeStack)> {
         trace!("Updating stale nodes from old extension node...");
-        let target_node_hash = new_stack[0].get_hash()?;
-        let updated_extension_node = Node::get_new_extension_node(
-            current_node.get_key(),
-            convert_h256_to_bytes(target_node_hash),
-        )?;
+        let updated_extension_node =
+            Node::get_new_extension_node(current_node.get_key(), new_stack[0].get_reference()?)?;
         new_stack.insert(0, updated_extension_node);
         stack_to_delete.push(current_node);
         self.update_stale_nodes(target_key, old_stack, new_stack, stack_to_delete)
@@ -461,9 +407,9 @@ impl Trie {
      *
      * Here we take the old branch node and update it to contain the next node
      * in line's hash, placed at the correct index in the branches. Which
-     * latter is calculated by finding out how much of the target key so far
-     * is accounted for in the new_node stack, and getting the nibble
-     * immediately before that.
+     * latter is the nibble of the target key immediately after the part of it
+     * consumed by the nodes above this branch, which are those still left in
+     * the `old_stack`.
      *
      * This updated branch node is then unshifted into the `new_node` stack,
      * and the old branch node condemned to the `stack_to_delete` for later
@@ -471,23 +417,22 @@ impl Trie {
      *
      */
     fn update_nodes_from_old_branch_node(
-        self,
+        &self,
         target_key: Nibbles,
         current_node: Node,
         old_stack: NodeStack,
         mut new_stack: NodeStack,
         mut stack_to_delete: NodeStack,
-    ) -> Result<(Self, Nibbles, NodeStack, NodeStack, NodeStack)> {
+    ) -> Result<(Nibbles, NodeStack, NodeStack, NodeStack)> {
         trace!("Updating stale nodes from old branch node...");
-        let target_node_hash = new_stack[0].get_hash()?;
-        let key_partial_length = get_key_length_accounted_for_in_stack(&new_stack);
-        let nibble_index = target_key.len() - key_partial_length - 1;
+        let target_node_reference = new_stack[0].get_reference()?;
+        let nibble_index = get_key_length_accounted_for_in_stack(&old_stack);
         let byte = get_nibble_at_index(&target_key, nibble_index)?;
         let nibble = get_nibbles_from_bytes(vec![byte]);
         let branch_index = convert_nibble_to_usize(nibble);
         let updated_node = current_node
             .clone()
-            .update_branch_at_index(Some(convert_h256_to_bytes(target_node_hash)), branch_index)?;
+            .update_branch_at_index(Some(target_node_reference), branch_index)?;
         new_stack.insert(0, updated_node);
         stack_to_delete.push(current_node);
         self.update_stale_nodes(target_key, old_stack, new_stack, stack_to_delete)
@@ -499,27 +444,42 @@ impl Trie {
      * former in to the database and removing the latter. Before putting the
      * final node in the database, its hash is used to update the trie root.
      *
+     * Inline nodes live only within their parents and so are not saved,
+     * except for the root which is always referred to by its hash. Nor are
+     * they removed, since they were never saved, unless the stale node is the
+     * old root. Since the store counts references to each node, every removal
+     * here must match an earlier save, else a node still referred to from
+     * elsewhere could go missing.
+     *
      */
     fn update_trie_database(
-        self,
+        &mut self,
         mut new_stack: NodeStack,
         mut stack_to_delete: NodeStack,
-    ) -> Result<Self> {
+    ) -> Result<()> {
         match !stack_to_delete.is_empty() {
             true => {
                 let node = stack_to_delete
                     .pop()
                     .ok_or_else(|| NoneError("Could not pop stack!".into()))?;
-                trace!(
-                    "Removing {} from database w/ hash: {}",
-                    node.get_type(),
-                    node.get_hash()?
-                );
-                self.remove_node_from_database(node)
-                    .and_then(|new_self| new_self.update_trie_database(new_stack, stack_to_delete))
+                match node.is_inline()? && node.get_hash()? != self.root {
+                    true => {
+                        trace!("Stale {} is inline ∴ nothing to remove...", node.get_type());
+                        self.update_trie_database(new_stack, stack_to_delete)
+                    }
+                    false => {
+                        trace!(
+                            "Removing {} from database w/ hash: {}",
+                            node.get_type(),
+                            node.get_hash()?
+                        );
+                        self.remove_node_from_database(node)
+                            .and_then(|_| self.update_trie_database(new_stack, stack_to_delete))
+                    }
+                }
             }
             false => match new_stack.len() {
-                0 => Ok(self),
+                0 => Ok(()),
                 1 => {
                     let node = new_stack
                         .pop()
@@ -530,73 +490,409 @@ impl Trie {
                         node.get_type(),
                         next_root_hash
                     );
-                    self.put_node_in_database(node).and_then(|new_self| {
+                    self.put_node_in_database(node).and_then(|_| {
                         trace!("Updating root hash to {}\n", next_root_hash);
-                        new_self.update_root_hash(next_root_hash)
+                        self.update_root_hash(next_root_hash)
                     })
                 }
                 _ => {
                     let node = new_stack
                         .pop()
                         .ok_or_else(|| NoneError("Could not pop stack!".into()))?;
-                    trace!(
-                        "Putting new {} in database w/ hash: {}",
-                        node.get_type(),
-                        node.get_hash()?
-                    );
-                    self.put_node_in_database(node).and_then(|new_self| {
-                        new_self.update_trie_database(new_stack, stack_to_delete)
-                    })
+                    match node.is_inline()? {
+                        true => {
+                            trace!("New {} is inline ∴ not saving it...", node.get_type());
+                            self.update_trie_database(new_stack, stack_to_delete)
+                        }
+                        false => {
+                            trace!(
+                                "Putting new {} in database w/ hash: {}",
+                                node.get_type(),
+                                node.get_hash()?
+                            );
+                            self.put_node_in_database(node)
+                                .and_then(|_| self.update_trie_database(new_stack, stack_to_delete))
+                        }
+                    }
                 }
             },
         }
     }
 
-    pub fn find(self, target_key: Nibbles) -> Result<(Self, Nibbles, NodeStack, Nibbles)> {
-        get_node_from_database(&self.database, &self.root).and_then(|maybe_node| match maybe_node {
-            Some(node) => Trie::find_path(self, target_key.clone(), vec![node], target_key),
-            None => Err(AppError::Custom(
-                "✘ Find Error: Could not find root node in db!".to_string(),
+    pub fn delete(&mut self, key: Nibbles) -> Result<()> {
+        let key = self.get_path_from_key(key)?;
+        trace!("Deleting value from trie under path: {:?}", key);
+        let (target_key, mut found_stack, remaining_key) = self.find_from_root(key)?;
+        match found_stack.pop() {
+            Some(node) if is_full_match(&target_key, &found_stack, &node, &remaining_key) => {
+                self.process_deletion_from_found_node(target_key, node, found_stack)
+            }
+            _ => {
+                trace!("Key not in trie ∴ nothing to delete!");
+                Ok(())
+            }
+        }
+    }
+    /**
+     * Deleting From the Found Node:
+     *
+     * A leaf holding the value is simply removed, leaving nothing in its
+     * place. A branch holding the value instead has its value removed, and is
+     * then collapsed per `collapse_branch_node`, since without it the branch
+     * may have too few children to remain one. Either way, whatever is left
+     * in place of the found node is then propagated up through its ancestors.
+     *
+     */
+    fn process_deletion_from_found_node(
+        &mut self,
+        target_key: Nibbles,
+        found_node: Node,
+        found_stack: NodeStack,
+    ) -> Result<()> {
+        let stack_to_delete = vec![found_node.clone()];
+        match found_node {
+            Node::Leaf(_) => {
+                trace!("Removing leaf node holding value...");
+                self.update_nodes_after_deletion(
+                    target_key,
+                    found_stack,
+                    None,
+                    Vec::new(),
+                    stack_to_delete,
+                )
+            }
+            Node::Branch(_) => {
+                trace!("Removing value from branch node...");
+                let (replacement, new_stack, stack_to_delete) =
+                    found_node.update_branch_value(None).and_then(|branch| {
+                        self.collapse_branch_node(branch, Vec::new(), stack_to_delete)
+                    })?;
+                self.update_nodes_after_deletion(
+                    target_key,
+                    found_stack,
+                    replacement,
+                    new_stack,
+                    stack_to_delete,
+                )
+            }
+            Node::Null | Node::Extension(_) => Err(AppError::Custom(
+                "✘ Delete Error: Found node holds no value!".to_string(),
             )),
-        })
+        }
+    }
+    /**
+     * Updating Nodes After a Deletion:
+     *
+     * Here we walk back up the found stack, updating each ancestor to account
+     * for whatever replaced the node beneath it, which may be nothing at all.
+     *
+     * A branch ancestor has its child at the index of the nibble it consumes
+     * updated, and is then collapsed per `collapse_branch_node`.
+     *
+     * An extension ancestor merges with a leaf or extension beneath it, their
+     * paths being concatenated, or is otherwise updated to point at the branch
+     * beneath it.
+     *
+     * Each ancestor is condemned to the `stack_to_delete`, and whatever
+     * replaces it is carried up to the next. Once past the root, the new nodes
+     * are saved, the stale ones removed, and the root hash updated.
+     *
+     */
+    fn update_nodes_after_deletion(
+        &mut self,
+        target_key: Nibbles,
+        mut old_stack: NodeStack,
+        replacement: Option<Node>,
+        mut new_stack: NodeStack,
+        mut stack_to_delete: NodeStack,
+    ) -> Result<()> {
+        match old_stack.pop() {
+            None => match replacement {
+                Some(root_node) => {
+                    new_stack.insert(0, root_node);
+                    self.update_trie_database(new_stack, stack_to_delete)
+                }
+                None => {
+                    trace!("Nothing left in trie ∴ resetting root hash...");
+                    self.update_trie_database(new_stack, stack_to_delete)
+                        .and_then(|_| self.update_root_hash(HASHED_NULL_NODE))
+                }
+            },
+            Some(current_node) => {
+                stack_to_delete.push(current_node.clone());
+                match current_node {
+                    Node::Branch(_) => {
+                        trace!("Updating branch node after deletion...");
+                        let nibble_index = get_key_length_accounted_for_in_stack(&old_stack);
+                        let byte = get_nibble_at_index(&target_key, nibble_index)?;
+                        let branch_index =
+                            convert_nibble_to_usize(get_nibbles_from_bytes(vec![byte]));
+                        let pointer = match &replacement {
+                            Some(node) => Some(node.get_reference()?),
+                            None => None,
+                        };
+                        new_stack.extend(replacement);
+                        let (collapsed_node, new_stack, stack_to_delete) = current_node
+                            .update_branch_at_index(pointer, branch_index)
+                            .and_then(|branch| {
+                                self.collapse_branch_node(branch, new_stack, stack_to_delete)
+                            })?;
+                        self.update_nodes_after_deletion(
+                            target_key,
+                            old_stack,
+                            collapsed_node,
+                            new_stack,
+                            stack_to_delete,
+                        )
+                    }
+                    Node::Extension(_) => {
+                        trace!("Updating extension node after deletion...");
+                        let updated_node = match replacement {
+                            None => None,
+                            Some(child) => match child {
+                                Node::Branch(_) => {
+                                    let updated_extension = Node::get_new_extension_node(
+                                        current_node.get_key(),
+                                        child.get_reference()?,
+                                    )?;
+                                    new_stack.push(child);
+                                    Some(updated_extension)
+                                }
+                                _ => Some(get_node_with_prefixed_path(
+                                    &current_node.get_key(),
+                                    &child,
+                                )?),
+                            },
+                        };
+                        self.update_nodes_after_deletion(
+                            target_key,
+                            old_stack,
+                            updated_node,
+                            new_stack,
+                            stack_to_delete,
+                        )
+                    }
+                    Node::Null | Node::Leaf(_) => Err(AppError::Custom(
+                        "✘ Error updating nodes after deletion: Wrong node type!".to_string(),
+                    )),
+                }
+            }
+        }
+    }
+    /**
+     * Collapsing a Branch Node:
+     *
+     * After a deletion, a branch node has the following cases to consider:
+     *
+     * 1) No children & no value.
+     * 2) No children but a value.
+     * 3) A single child & no value.
+     * 4) Anything else.
+     *
+     * In the first case nothing remains of the branch. In the second it
+     * becomes a leaf holding the value under an empty path.
+     *
+     * In the third case, a leaf or extension child has its path prefixed with
+     * the nibble the branch consumed for it, and so replaces the branch. The
+     * now redundant child is dropped from the `new_stack` if it was pending,
+     * or else condemned to the `stack_to_delete`. A branch child instead gets
+     * a new extension of that single nibble pointing at it.
+     *
+     * In the fourth case the branch remains as it is.
+     *
+     */
+    fn collapse_branch_node(
+        &self,
+        branch_node: Node,
+        mut new_stack: NodeStack,
+        mut stack_to_delete: NodeStack,
+    ) -> Result<(Option<Node>, NodeStack, NodeStack)> {
+        let branch = match &branch_node {
+            Node::Branch(branch) => branch.clone(),
+            Node::Null | Node::Leaf(_) | Node::Extension(_) => {
+                return Err(AppError::Custom(
+                    "✘ Cannot collapse node: Not a branch node!".to_string(),
+                ))
+            }
+        };
+        let children = branch
+            .branches
+            .iter()
+            .enumerate()
+            .filter_map(|(i, child)| child.clone().map(|pointer| (i, pointer)))
+            .collect::<Vec<(usize, Bytes)>>();
+        match (children.len(), branch.value) {
+            (0, None) => {
+                trace!("Branch node is empty ∴ removing it...");
+                Ok((None, new_stack, stack_to_delete))
+            }
+            (0, Some(value)) => {
+                trace!("Branch node has only a value ∴ collapsing to leaf...");
+                Node::get_new_leaf_node(EMPTY_NIBBLES, value)
+                    .map(|leaf| (Some(leaf), new_stack, stack_to_delete))
+            }
+            (1, None) => {
+                let (index, pointer) = &children[0];
+                let nibble = get_nibbles_from_offset_bytes(vec![*index as Byte]);
+                let child = self.get_child_node_from_stack_or_database(pointer, &new_stack)?;
+                match child {
+                    Node::Branch(_) => {
+                        trace!("Branch node has single branch child ∴ collapsing to extension...");
+                        Node::get_new_extension_node(nibble, pointer.clone())
+                            .map(|extension| (Some(extension), new_stack, stack_to_delete))
+                    }
+                    _ => {
+                        trace!("Branch node has single child ∴ merging with it...");
+                        let merged_node = get_node_with_prefixed_path(&nibble, &child)?;
+                        match new_stack.iter().position(|node| node == &child) {
+                            Some(i) => {
+                                new_stack.remove(i);
+                            }
+                            None => stack_to_delete.push(child),
+                        };
+                        Ok((Some(merged_node), new_stack, stack_to_delete))
+                    }
+                }
+            }
+            _ => Ok((Some(branch_node), new_stack, stack_to_delete)),
+        }
+    }
+
+    fn get_child_node_from_stack_or_database(
+        &self,
+        pointer: &[Byte],
+        new_stack: &[Node],
+    ) -> Result<Node> {
+        match new_stack
+            .iter()
+            .find(|node| node.get_reference().ok().as_deref() == Some(pointer))
+        {
+            Some(node) => Ok(node.clone()),
+            None => get_existing_node_from_reference(&self.database, pointer),
+        }
+    }
+
+    /**
+     * An empty trie has no root node saved, its root being the hash of the
+     * null node, and so that node stands in for it.
+     *
+     */
+    fn get_root_node(&self) -> Result<Node> {
+        match self.root == HASHED_NULL_NODE {
+            true => Ok(Node::Null),
+            false => get_node_from_database(&self.database, &self.root)?
+                .ok_or(AppError::MissingNodeError(self.root)),
+        }
+    }
+
+    pub fn get(&self, key: Nibbles) -> Result<Option<Bytes>> {
+        self.get_root_node()
+            .and_then(|root_node| self.get_value_from_node(root_node, self.get_path_from_key(key)?))
+    }
+    /**
+     *
+     * Getting a Value Onwards from a Node:
+     *
+     * At a leaf node, the value is only ours if the leaf's path consumes all
+     * of the key that remains. Otherwise the key diverges from the only path
+     * available and so nothing is stored under it.
+     *
+     * At an extension node, we recurse into the child it points to if the
+     * extension's path is a prefix of the remaining key, with that prefix
+     * sliced off. Otherwise the key diverges and nothing is stored under it.
+     *
+     * At a branch node with no key remaining, the value is the branch's own
+     * value, which may be empty. Otherwise we recurse into the child at the
+     * index of the first nibble of the remaining key, if there is one.
+     *
+     */
+    fn get_value_from_node(&self, node: Node, key: Nibbles) -> Result<Option<Bytes>> {
+        match node {
+            Node::Null => Ok(None),
+            Node::Leaf(leaf) => get_common_prefix_nibbles(key, leaf.path_nibbles.clone()).map(
+                |(_, remaining_key, remaining_node_key)| match remaining_key.len() == 0
+                    && remaining_node_key.len() == 0
+                {
+                    true => Some(leaf.value),
+                    false => None,
+                },
+            ),
+            Node::Extension(extension) => {
+                get_common_prefix_nibbles(key, extension.path_nibbles.clone()).and_then(
+                    |(_, remaining_key, remaining_node_key)| match remaining_node_key.len() {
+                        0 => self.get_value_from_child_node(&extension.value, remaining_key),
+                        _ => Ok(None),
+                    },
+                )
+            }
+            Node::Branch(branch) => match key.len() {
+                0 => Ok(branch.value),
+                _ => split_at_first_nibble(&key).and_then(|(first_nibble, remaining_nibbles)| {
+                    match &branch.branches[convert_nibble_to_usize(first_nibble)] {
+                        Some(child_reference) => {
+                            self.get_value_from_child_node(child_reference, remaining_nibbles)
+                        }
+                        None => Ok(None),
+                    }
+                }),
+            },
+        }
+    }
+
+    fn get_value_from_child_node(
+        &self,
+        child_reference: &[Byte],
+        key: Nibbles,
+    ) -> Result<Option<Bytes>> {
+        get_existing_node_from_reference(&self.database, child_reference)
+            .and_then(|child_node| self.get_value_from_node(child_node, key))
+    }
+
+    pub fn find(&self, key: Nibbles) -> Result<(Nibbles, NodeStack, Nibbles)> {
+        self.get_path_from_key(key)
+            .and_then(|target_key| self.find_from_root(target_key))
+    }
+
+    fn find_from_root(&self, target_key: Nibbles) -> Result<(Nibbles, NodeStack, Nibbles)> {
+        self.get_root_node()
+            .and_then(|node| self.find_path(target_key.clone(), vec![node], target_key))
     }
 
     fn find_path(
-        self,
+        &self,
         target_key: Nibbles,
         mut found_stack: NodeStack,
         remaining_key: Nibbles,
-    ) -> Result<(Self, Nibbles, NodeStack, Nibbles)> {
+    ) -> Result<(Nibbles, NodeStack, Nibbles)> {
         match found_stack.pop() {
             None => {
                 trace!("No node in top of stack");
-                Ok((self, target_key, found_stack, remaining_key))
+                Ok((target_key, found_stack, remaining_key))
             }
-            Some(current_node) => match current_node.get_type() {
-                "leaf" => Self::continue_finding_from_leaf(
-                    self,
+            Some(current_node) => match current_node {
+                Node::Null => {
+                    trace!("Null node found ∴ trie is empty");
+                    found_stack.push(current_node);
+                    Ok((target_key, found_stack, remaining_key))
+                }
+                Node::Leaf(_) => self.continue_finding_from_leaf(
                     target_key,
                     current_node,
                     found_stack,
                     remaining_key,
                 ),
-                "branch" => Self::continue_finding_from_branch(
-                    self,
+                Node::Branch(branch) => self.continue_finding_from_branch(
                     target_key,
-                    current_node,
+                    branch,
                     found_stack,
                     remaining_key,
                 ),
-                "extension" => Self::continue_finding_from_extension(
-                    self,
+                Node::Extension(_) => self.continue_finding_from_extension(
                     target_key,
                     current_node,
                     found_stack,
                     remaining_key,
                 ),
-                _ => Err(AppError::Custom(
-                    "✘ Find Error: Node type not recognized!".to_string(),
-                )),
             },
         }
     }
@@ -620,26 +916,28 @@ impl Trie {
      *
      */
     fn continue_finding_from_leaf(
-        self,
+        &self,
         target_key: Nibbles,
         leaf_node: Node,
         mut found_stack: NodeStack,
         key: Nibbles,
-    ) -> Result<(Self, Nibbles, NodeStack, Nibbles)> {
+    ) -> Result<(Nibbles, NodeStack, Nibbles)> {
         trace!("Leaf node found");
-        get_common_prefix_nibbles(key.clone(), leaf_node.get_key()).map(|(_, remaining_key, _)| {
-            found_stack.push(leaf_node);
-            match remaining_key.len() {
-                0 => {
-                    trace!("Wohoo! Leaf node matches fully!");
-                    (self, target_key, found_stack, EMPTY_NIBBLES)
-                }
-                _ => {
-                    trace!("Leaf node has some | no match");
-                    (self, target_key, found_stack, key)
+        get_common_prefix_nibbles(key.clone(), leaf_node.get_key()).map(
+            |(_, remaining_key, remaining_node_key)| {
+                found_stack.push(leaf_node);
+                match remaining_key.len() + remaining_node_key.len() {
+                    0 => {
+                        trace!("Wohoo! Leaf node matches fully!");
+                        (target_key, found_stack, EMPTY_NIBBLES)
+                    }
+                    _ => {
+                        trace!("Leaf node has some | no match");
+                        (target_key, found_stack, key)
+                    }
                 }
-            }
-        })
+            },
+        )
     }
     /**
      *
@@ -662,42 +960,37 @@ impl Trie {
      *
      */
     fn continue_finding_from_extension(
-        self,
+        &self,
         target_key: Nibbles,
         extension_node: Node,
         mut found_stack: NodeStack,
         key: Nibbles,
-    ) -> Result<(Self, Nibbles, NodeStack, Nibbles)> {
+    ) -> Result<(Nibbles, NodeStack, Nibbles)> {
         trace!("Extension node found");
         get_common_prefix_nibbles(key.clone(), extension_node.get_key()).and_then(
             |(common_prefix, remaining_key, remaining_node_key)| {
-                let next_node_hash = &convert_bytes_to_h256(
-                    &extension_node
-                        .get_value()
-                        .ok_or_else(|| NoneError("Could not unwrap extension node!".into()))?,
-                )?;
+                let next_node_reference = extension_node
+                    .get_value()
+                    .ok_or_else(|| NoneError("Could not unwrap extension node!".into()))?;
                 found_stack.push(extension_node);
                 match common_prefix.len() {
                     0 => {
                         trace!("Extension & key have no common prefix");
-                        Ok((self, target_key, found_stack, key))
+                        Ok((target_key, found_stack, key))
                     }
                     _ => match remaining_node_key.len() > 0 {
                         true => {
                             trace!("Extension partial match");
-                            Ok((self, target_key, found_stack, key))
+                            Ok((target_key, found_stack, key))
                         }
                         false => {
                             trace!("Extension full match, continuing...");
-                            match get_node_from_database(&self.database, next_node_hash)? {
-                                Some(next_node) => {
-                                    found_stack.push(next_node);
-                                    Self::find_path(self, target_key, found_stack, remaining_key)
-                                }
-                                None => Err(AppError::Custom(
-                                    "✘ Find Error: Extension child not in db!".to_string(),
-                                )),
-                            }
+                            let next_node = get_existing_node_from_reference(
+                                &self.database,
+                                &next_node_reference,
+                            )?;
+                            found_stack.push(next_node);
+                            self.find_path(target_key, found_stack, remaining_key)
                         }
                     },
                 }
@@ -708,8 +1001,11 @@ impl Trie {
      *
      * Finding Onwards from a Branch Node:
      *
-     * When arriving at a branch node, we take our target key and slice off the
-     * first nibble. This is then used as the index for inspecting the branches
+     * When arriving at a branch node with no key remaining, the branch itself
+     * is the end of our search, since it's where the value for the target key
+     * lives, & so the stack is returned along with the empty key.
+     *
+     * Otherwise we take our target key and slice off the first nibble. This is then used as the index for inspecting the branches
      * children, at which point there are two cases:
      *
      * 1) The child is empty.
@@ -725,88 +1021,198 @@ impl Trie {
      * 2) The child is an inline node.
      *
      * In the first case we search the database for the node pointed to by that
-     * hash and then add it to the stack after first adding the branch node
-     * we're currently looking at back to the stack. We then recurse back into
-     * the `find_path` function with our updated stack and the target key.
-     *
-     * The second case is not yet currently handled. // TODO!
+     * hash, whilst in the second the child is decoded from the reference
+     * itself. Either way it's added to the stack after first adding the branch
+     * node we're currently looking at back to the stack. We then recurse back
+     * into the `find_path` function with our updated stack and the target key.
      *
      */
     fn continue_finding_from_branch(
-        self,
+        &self,
         target_key: Nibbles,
-        branch_node: Node,
+        branch: BranchNode,
         mut found_stack: NodeStack,
         key: Nibbles,
-    ) -> Result<(Self, Nibbles, NodeStack, Nibbles)> {
+    ) -> Result<(Nibbles, NodeStack, Nibbles)> {
         trace!("Branch node found");
-        found_stack.push(branch_node.clone());
-        split_at_first_nibble(&key).and_then(|(first_nibble, remaining_nibbles)| match &branch_node
-            .branch
-            .ok_or_else(|| NoneError("Could not unwrap branch!".into()))?
-            .branches[convert_nibble_to_usize(first_nibble)]
-        {
-            None => {
-                trace!("No hash at next nibble index in branch");
-                Ok((self, target_key, found_stack, key))
-            }
-            Some(bytes) => {
-                match get_node_from_database(&self.database, &convert_bytes_to_h256(bytes)?)? {
-                    Some(next_node) => {
-                        trace!(
-                            "Next node retrieved from hash in {}",
-                            "branch, continuing..."
-                        );
-                        found_stack.push(next_node);
-                        Self::find_path(self, target_key, found_stack, remaining_nibbles)
-                    }
-                    None => Err(AppError::Custom(
-                        "✘ Find Error: Branch child not in db!".to_string(),
-                    )),
+        found_stack.push(Node::Branch(branch.clone()));
+        if key.len() == 0 {
+            trace!("No key remaining ∴ branch node's value is the target");
+            return Ok((target_key, found_stack, key));
+        };
+        split_at_first_nibble(&key).and_then(|(first_nibble, remaining_nibbles)| {
+            match &branch.branches[convert_nibble_to_usize(first_nibble)] {
+                None => {
+                    trace!("No hash at next nibble index in branch");
+                    Ok((target_key, found_stack, key))
+                }
+                Some(reference) => {
+                    let next_node = get_existing_node_from_reference(&self.database, reference)?;
+                    trace!(
+                        "Next node retrieved from reference in {}",
+                        "branch, continuing..."
+                    );
+                    found_stack.push(next_node);
+                    self.find_path(target_key, found_stack, remaining_nibbles)
                 }
             }
         })
     }
 
-    pub fn update_root_hash(mut self, new_hash: H256) -> Result<Self> {
+    pub fn update_root_hash(&mut self, new_hash: H256) -> Result<()> {
         self.root = new_hash;
-        Ok(self)
+        Ok(())
     }
 
-    fn put_node_in_database(self, node: Node) -> Result<Self> {
-        Ok(Trie {
-            root: self.root,
-            database: put_thing_in_database(
-                self.database,
-                node.get_hash()?,
-                node.get_rlp_encoding()?,
-            )?,
-        })
+    fn put_node_in_database(&mut self, node: Node) -> Result<()> {
+        put_thing_in_database(
+            &mut self.database,
+            node.get_hash()?,
+            node.get_rlp_encoding()?,
+        )
     }
 
-    fn remove_node_from_database(self, node: Node) -> Result<Self> {
-        Ok(Trie {
-            root: self.root,
-            database: remove_thing_from_database(self.database, &node.get_hash()?)?,
-        })
+    fn remove_node_from_database(&mut self, node: Node) -> Result<()> {
+        remove_thing_from_database(&mut self.database, &node.get_hash()?)
+    }
+}
+
+/**
+ * Splitting a Node Where the Remaining Key Diverges From its Path:
+ *
+ * A branch is created at the point of divergence. The existing leaf or
+ * extension is re-homed beneath it, with its path shortened by the common
+ * prefix & the nibble the branch now consumes. An extension left with no
+ * path at all is done away with, the branch instead pointing directly at
+ * what the extension pointed at. A new leaf holding the value is put beneath
+ * the branch alongside it.
+ *
+ * Where either the remaining key or the leaf's path is wholly consumed by
+ * the common prefix, its value is instead stored in the branch itself.
+ *
+ * Finally, if there is a common prefix, an extension consuming it is created
+ * to point at the new branch. The returned stack is ordered top-down.
+ *
+ */
+fn get_nodes_from_split(
+    node: &Node,
+    common_prefix: Nibbles,
+    key_remainder: Nibbles,
+    node_key_remainder: Nibbles,
+    value: Bytes,
+) -> Result<NodeStack> {
+    let node_value = node
+        .get_value()
+        .ok_or_else(|| NoneError("Could not get node value!".into()))?;
+    let mut child_stack: NodeStack = Vec::new();
+    let mut branch = match (key_remainder.len(), node_key_remainder.len()) {
+        (0, 0) => {
+            return Err(AppError::Custom(
+                "✘ Cannot split node: Key fully matches its path!".to_string(),
+            ))
+        }
+        (_, 0) if !matches!(node, Node::Leaf(_)) => {
+            return Err(AppError::Custom(
+                "✘ Cannot split node: Key fully consumes extension path!".to_string(),
+            ))
+        }
+        (0, _) => Node::get_new_branch_node(Some(value.clone()))?,
+        (_, 0) => Node::get_new_branch_node(Some(node_value.clone()))?,
+        _ => Node::get_new_branch_node(None)?,
+    };
+    if node_key_remainder.len() > 0 {
+        let (node_first_nibble, node_nibbles) = split_at_first_nibble(&node_key_remainder)?;
+        let pointer = match (node, node_nibbles.len()) {
+            (Node::Leaf(_), _) => {
+                let new_leaf = Node::get_new_leaf_node(node_nibbles, node_value)?;
+                let new_leaf_reference = new_leaf.get_reference()?;
+                child_stack.push(new_leaf);
+                new_leaf_reference
+            }
+            (_, 0) => node_value,
+            _ => {
+                let new_ext = Node::get_new_extension_node(node_nibbles, node_value)?;
+                let new_ext_reference = new_ext.get_reference()?;
+                child_stack.push(new_ext);
+                new_ext_reference
+            }
+        };
+        branch = branch
+            .update_branch_at_index(Some(pointer), convert_nibble_to_usize(node_first_nibble))?;
+    };
+    if key_remainder.len() > 0 {
+        let (key_first_nibble, key_nibbles) = split_at_first_nibble(&key_remainder)?;
+        let new_leaf = Node::get_new_leaf_node(key_nibbles, value)?;
+        branch = branch.update_branch_at_index(
+            Some(new_leaf.get_reference()?),
+            convert_nibble_to_usize(key_first_nibble),
+        )?;
+        child_stack.push(new_leaf);
+    };
+    let mut new_stack: NodeStack = match common_prefix.len() {
+        0 => Vec::new(),
+        _ => vec![Node::get_new_extension_node(
+            common_prefix,
+            branch.get_reference()?,
+        )?],
+    };
+    new_stack.push(branch);
+    new_stack.append(&mut child_stack);
+    Ok(new_stack)
+}
+
+pub fn get_secure_trie_key(key: &[Byte]) -> Result<Nibbles> {
+    keccak_hash_bytes(key).map(|hash| get_nibbles_from_bytes(convert_h256_to_bytes(hash)))
+}
+
+fn get_node_with_prefixed_path(prefix: &Nibbles, node: &Node) -> Result<Node> {
+    let path = concatenate_nibbles(prefix, &node.get_key())?;
+    let value = node
+        .get_value()
+        .ok_or_else(|| NoneError("Could not get node value!".into()))?;
+    match node {
+        Node::Leaf(_) => Node::get_new_leaf_node(path, value),
+        Node::Extension(_) => Node::get_new_extension_node(path, value),
+        Node::Null | Node::Branch(_) => Err(AppError::Custom(
+            "✘ Cannot prefix path of node without one!".to_string(),
+        )),
     }
 }
 
+fn is_full_match(
+    target_key: &Nibbles,
+    found_stack: &[Node],
+    found_node: &Node,
+    remaining_key: &Nibbles,
+) -> bool {
+    // NOTE: A key ending above a leaf also leaves no key remaining, so we
+    // check the nodes found consumed exactly the whole of the target key.
+    remaining_key.len() == 0
+        && match found_node {
+            Node::Leaf(_) => {
+                get_key_length_accounted_for_in_stack(found_stack) + found_node.get_key_length()
+                    == target_key.len()
+            }
+            Node::Branch(branch) => branch.value.is_some(),
+            Node::Null | Node::Extension(_) => false,
+        }
+}
+
 fn get_key_length_accounted_for_in_stack(node_stack: &[Node]) -> usize {
     node_stack.iter().map(|node| node.get_key_length()).sum()
 }
 
-pub fn put_in_trie_recursively(
-    trie: Trie,
+pub fn put_in_trie_recursively<D: NodeStore>(
+    mut trie: Trie<D>,
     key_value_tuples: Vec<(Nibbles, Bytes)>,
     i: usize,
-) -> Result<Trie> {
+) -> Result<Trie<D>> {
     match i == key_value_tuples.len() {
         true => Ok(trie),
         false => {
             trace!("Putting item #{} in trie recursively...", i + 1);
             trie.put(key_value_tuples[i].0.clone(), key_value_tuples[i].1.clone())
-                .and_then(|new_trie| put_in_trie_recursively(new_trie, key_value_tuples, i + 1))
+                .and_then(|_| put_in_trie_recursively(trie, key_value_tuples, i + 1))
         }
     }
 }
@@ -814,20 +1220,33 @@ pub fn put_in_trie_recursively(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::HASH_LENGTH;
+    use crate::get_branch_from_trie::{
+        convert_usize_index_to_trie_key, get_branch_from_trie, get_branches_from_trie,
+        get_exclusion_branch_from_trie,
+    };
     use crate::get_database::get_thing_from_database;
+    use crate::get_keccak_hash::keccak_hash_bytes;
+    use crate::node_store::FileNodeStore;
     use crate::rlp_codec::get_rlp_encoded_receipts_and_nibble_tuples;
     use crate::test_utils::{
-        convert_hex_string_to_nibbles, get_sample_branch_node, get_sample_extension_node,
-        get_sample_leaf_node, get_sample_receipts, get_sample_tx_hashes_1, get_sample_tx_hashes_2,
-        get_sample_tx_hashes_3, RECEIPTS_ROOT_1, RECEIPTS_ROOT_2, RECEIPTS_ROOT_3,
-        SAMPLE_RECEIPT_JSONS_1_PATH, SAMPLE_RECEIPT_JSONS_2_PATH, SAMPLE_RECEIPT_JSONS_3_PATH,
+        convert_hex_string_to_nibbles, get_rlp_encoded_nodes_from_node_stack,
+        get_sample_account_proof, get_sample_branch_node, get_sample_extension_node,
+        get_sample_indexed_value, get_sample_inline_key_value_tuples, get_sample_leaf_node,
+        get_sample_receipts, get_sample_receipts_1_trie_and_key_value_tuples,
+        get_sample_trie_with_inline_nodes, get_sample_tx_hashes_1, get_sample_tx_hashes_2,
+        get_sample_tx_hashes_3, get_temporary_directory, INLINE_TRIE_ROOT, RECEIPTS_ROOT_1,
+        RECEIPTS_ROOT_2, RECEIPTS_ROOT_3, SAMPLE_RECEIPT_JSONS_1_PATH, SAMPLE_RECEIPT_JSONS_2_PATH,
+        SAMPLE_RECEIPT_JSONS_3_PATH,
     };
     use crate::utils::{convert_h256_to_prefixed_hex, convert_hex_to_h256};
+    use crate::verify_proof::verify_proof_nodes;
+    use std::fs;
 
     #[test]
     fn should_get_empty_trie() {
         let trie = Trie::get_new_trie().unwrap();
-        assert!(trie.database.is_empty());
+        assert!(trie.database.nodes.is_empty());
         assert!(trie.root == HASHED_NULL_NODE);
     }
 
@@ -838,37 +1257,41 @@ mod tests {
         let expected_node = Node::get_new_leaf_node(key.clone(), value.clone()).unwrap();
         let expected_db_key = expected_node.get_hash().unwrap();
         let expected_thing_from_db = expected_node.get_rlp_encoding().unwrap();
-        let trie = Trie::get_new_trie().unwrap();
-        let result = trie.put(key, value).unwrap();
-        assert!(result.root == expected_node.get_hash().unwrap());
-        let thing_from_db = get_thing_from_database(&result.database, &expected_db_key).unwrap();
+        let mut trie = Trie::get_new_trie().unwrap();
+        trie.put(key, value).unwrap();
+        assert!(trie.root == expected_node.get_hash().unwrap());
+        let thing_from_db = get_thing_from_database(&trie.database, &expected_db_key)
+            .unwrap()
+            .unwrap();
         assert!(thing_from_db == expected_thing_from_db)
     }
 
     #[test]
     fn should_update_root_hash() {
-        let trie = Trie::get_new_trie().unwrap();
+        let mut trie = Trie::get_new_trie().unwrap();
         let old_hash = trie.root;
         let new_hash = convert_hex_to_h256(
             "a8780134f4add652b6e22e16a45b3436d3ecc293840fe8433f6fbcdc9ea8f16e".to_string(),
         )
         .unwrap();
         assert!(old_hash != new_hash);
-        let result = trie.update_root_hash(new_hash).unwrap();
-        assert!(result.root == new_hash);
-        assert!(result.root != old_hash);
+        trie.update_root_hash(new_hash).unwrap();
+        assert!(trie.root == new_hash);
+        assert!(trie.root != old_hash);
     }
 
     #[test]
     fn should_put_node_in_database_in_trie() {
         let node_key = convert_hex_string_to_nibbles("c0ffe".to_string()).unwrap();
         let node_value = vec![0xde, 0xca, 0xff];
-        let trie = Trie::get_new_trie().unwrap();
+        let mut trie = Trie::get_new_trie().unwrap();
         let node = Node::get_new_leaf_node(node_key.clone(), node_value.clone()).unwrap();
         let expected_result = node.get_rlp_encoding().unwrap();
         let node_hash = node.get_hash().unwrap();
-        let updated_trie = trie.put_node_in_database(node.clone()).unwrap();
-        let result = get_thing_from_database(&updated_trie.database, &node_hash).unwrap();
+        trie.put_node_in_database(node.clone()).unwrap();
+        let result = get_thing_from_database(&trie.database, &node_hash)
+            .unwrap()
+            .unwrap();
         assert!(result == expected_result);
     }
 
@@ -876,13 +1299,13 @@ mod tests {
     fn should_remove_node_from_database() {
         let node_key = convert_hex_string_to_nibbles("c0ffe".to_string()).unwrap();
         let node_value = vec![0xde, 0xca, 0xff];
-        let trie = Trie::get_new_trie().unwrap();
+        let mut trie = Trie::get_new_trie().unwrap();
         let node = Node::get_new_leaf_node(node_key.clone(), node_value.clone()).unwrap();
         let node_hash = node.get_hash().unwrap();
-        let updated_trie = trie.put_node_in_database(node.clone()).unwrap();
-        assert!(updated_trie.database.contains_key(&node_hash));
-        let resulting_trie = updated_trie.remove_node_from_database(node).unwrap();
-        assert!(!resulting_trie.database.contains_key(&node_hash));
+        trie.put_node_in_database(node.clone()).unwrap();
+        assert!(trie.database.nodes.contains_key(&node_hash));
+        trie.remove_node_from_database(node).unwrap();
+        assert!(!trie.database.nodes.contains_key(&node_hash));
     }
 
     #[test]
@@ -946,4 +1369,832 @@ mod tests {
         let root_hex = convert_h256_to_prefixed_hex(updated_trie.root).unwrap();
         assert!(root_hex == RECEIPTS_ROOT_3);
     }
+
+    fn get_sample_storage_key_value_tuples() -> Vec<(Nibbles, Bytes)> {
+        get_sample_account_proof()
+            .storage_proof
+            .iter()
+            .map(|storage_proof| {
+                (
+                    get_nibbles_from_bytes(convert_h256_to_bytes(storage_proof.key)),
+                    rlp::encode(&storage_proof.value),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_get_empty_secure_trie() {
+        let trie = Trie::get_new_secure_trie().unwrap();
+        assert!(trie.secure);
+        assert!(trie.database.nodes.is_empty());
+        assert!(trie.root == HASHED_NULL_NODE);
+    }
+
+    #[test]
+    fn should_put_thing_in_empty_secure_trie_under_hashed_key() {
+        let key = get_nibbles_from_bytes(vec![0xc0, 0xff, 0xee]);
+        let value = vec![0xde, 0xca, 0xff];
+        let hashed_key = get_secure_trie_key(&key.data).unwrap();
+        let expected_node = Node::get_new_leaf_node(hashed_key, value.clone()).unwrap();
+        let mut trie = Trie::get_new_secure_trie().unwrap();
+        trie.put(key, value).unwrap();
+        assert!(trie.root == expected_node.get_hash().unwrap());
+    }
+
+    #[test]
+    fn should_put_sample_storage_in_secure_trie_correctly() {
+        let expected_root = get_sample_account_proof().account.storage_root;
+        let trie = Trie::get_new_secure_trie().unwrap();
+        let result =
+            put_in_trie_recursively(trie, get_sample_storage_key_value_tuples(), 0).unwrap();
+        assert!(result.root == expected_root);
+    }
+
+    #[test]
+    fn should_not_get_storage_root_putting_sample_storage_in_non_secure_trie() {
+        let expected_root = get_sample_account_proof().account.storage_root;
+        let trie = Trie::get_new_trie().unwrap();
+        let result =
+            put_in_trie_recursively(trie, get_sample_storage_key_value_tuples(), 0).unwrap();
+        assert!(result.root != expected_root);
+    }
+
+    #[test]
+    fn should_find_verifiable_branch_in_secure_trie() {
+        let account_proof = get_sample_account_proof();
+        let storage_proof = &account_proof.storage_proof[2];
+        let trie = put_in_trie_recursively(
+            Trie::get_new_secure_trie().unwrap(),
+            get_sample_storage_key_value_tuples(),
+            0,
+        )
+        .unwrap();
+        let key = get_nibbles_from_bytes(convert_h256_to_bytes(storage_proof.key));
+        let (target_key, found_stack, remaining_key) = trie.find(key).unwrap();
+        assert!(remaining_key.len() == 0);
+        assert!(target_key == get_secure_trie_key(storage_proof.key.as_bytes()).unwrap());
+        let proof_nodes = found_stack
+            .iter()
+            .map(|node| node.get_rlp_encoding())
+            .collect::<Result<Vec<Bytes>>>()
+            .unwrap();
+        assert!(proof_nodes == storage_proof.proof);
+        let result = verify_proof_nodes(
+            &proof_nodes,
+            &account_proof.account.storage_root,
+            target_key,
+        )
+        .unwrap();
+        assert!(result == rlp::encode(&storage_proof.value));
+    }
+
+    #[test]
+    fn should_get_nothing_from_empty_trie() {
+        let key = convert_hex_string_to_nibbles("c0ffe".to_string()).unwrap();
+        let result = Trie::get_new_trie().unwrap().get(key).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn should_find_null_node_in_empty_trie() {
+        let key = convert_hex_string_to_nibbles("c0ffe".to_string()).unwrap();
+        let (_, found_stack, remaining_key) =
+            Trie::get_new_trie().unwrap().find(key.clone()).unwrap();
+        assert!(found_stack == vec![Node::Null]);
+        assert!(remaining_key == key);
+    }
+
+    #[test]
+    fn should_not_change_empty_trie_when_deleting_from_it() {
+        let key = convert_hex_string_to_nibbles("c0ffe".to_string()).unwrap();
+        let mut trie = Trie::get_new_trie().unwrap();
+        trie.delete(key).unwrap();
+        assert!(trie.root == HASHED_NULL_NODE);
+        assert!(trie.database.nodes.is_empty());
+    }
+
+    #[test]
+    fn should_get_value_from_single_leaf_trie() {
+        let key = convert_hex_string_to_nibbles("c0ffe".to_string()).unwrap();
+        let value = vec![0xde, 0xca, 0xff];
+        let mut trie = Trie::get_new_trie().unwrap();
+        trie.put(key.clone(), value.clone()).unwrap();
+        let result = trie.get(key).unwrap();
+        assert!(result == Some(value));
+    }
+
+    #[test]
+    fn should_get_nothing_for_key_diverging_from_leaf() {
+        let key = convert_hex_string_to_nibbles("c0ffe".to_string()).unwrap();
+        let other_key = convert_hex_string_to_nibbles("c0ffee".to_string()).unwrap();
+        let mut trie = Trie::get_new_trie().unwrap();
+        trie.put(key, vec![0xde, 0xca, 0xff]).unwrap();
+        let result = trie.get(other_key).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn should_get_every_receipt_from_sample_receipts_trie() {
+        let (trie, key_value_tuples) = get_sample_receipts_1_trie_and_key_value_tuples();
+        key_value_tuples.into_iter().for_each(|(key, value)| {
+            let result = trie.get(key).unwrap();
+            assert!(result == Some(value));
+        });
+    }
+
+    #[test]
+    fn should_get_nothing_for_index_not_in_sample_receipts_trie() {
+        let (trie, key_value_tuples) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let key = convert_usize_index_to_trie_key(key_value_tuples.len()).unwrap();
+        let result = trie.get(key).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn should_get_value_stored_in_branch_node() {
+        let branch_value = vec![0xc0, 0xff, 0xee];
+        let leaf = Node::get_new_leaf_node(
+            convert_hex_string_to_nibbles("fe".to_string()).unwrap(),
+            vec![0xde, 0xca, 0xff],
+        )
+        .unwrap();
+        let branch = Node::get_new_branch_node(Some(branch_value.clone()))
+            .and_then(|branch| {
+                branch.update_branch_at_index(Some(convert_h256_to_bytes(leaf.get_hash()?)), 0xa)
+            })
+            .unwrap();
+        let extension = Node::get_new_extension_node(
+            convert_hex_string_to_nibbles("ab".to_string()).unwrap(),
+            convert_h256_to_bytes(branch.get_hash().unwrap()),
+        )
+        .unwrap();
+        let root_hash = extension.get_hash().unwrap();
+        let mut trie = Trie::get_new_trie().unwrap();
+        trie.put_node_in_database(leaf).unwrap();
+        trie.put_node_in_database(branch).unwrap();
+        trie.put_node_in_database(extension).unwrap();
+        trie.update_root_hash(root_hash).unwrap();
+        let branch_key = convert_hex_string_to_nibbles("ab".to_string()).unwrap();
+        let leaf_key = convert_hex_string_to_nibbles("abafe".to_string()).unwrap();
+        let missing_key = convert_hex_string_to_nibbles("abbfe".to_string()).unwrap();
+        assert!(trie.get(branch_key).unwrap() == Some(branch_value));
+        assert!(trie.get(leaf_key).unwrap() == Some(vec![0xde, 0xca, 0xff]));
+        assert!(trie.get(missing_key).unwrap().is_none());
+    }
+
+    #[test]
+    fn should_get_value_from_secure_trie_by_unhashed_key() {
+        let account_proof = get_sample_account_proof();
+        let trie = put_in_trie_recursively(
+            Trie::get_new_secure_trie().unwrap(),
+            get_sample_storage_key_value_tuples(),
+            0,
+        )
+        .unwrap();
+        account_proof
+            .storage_proof
+            .iter()
+            .for_each(|storage_proof| {
+                let key = get_nibbles_from_bytes(convert_h256_to_bytes(storage_proof.key));
+                let result = trie.get(key).unwrap();
+                assert!(result == Some(rlp::encode(&storage_proof.value)));
+            });
+    }
+
+    #[test]
+    fn should_err_putting_odd_length_key_in_secure_trie() {
+        let expected_err = "✘ Secure trie keys must be a whole number of bytes!";
+        let key = convert_hex_string_to_nibbles("c0ffe".to_string()).unwrap();
+        match Trie::get_new_secure_trie().unwrap().put(key, vec![0xde]) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Putting odd-length key in secure trie should have failed!"),
+        }
+    }
+
+    fn get_prefix_key_value_tuples() -> Vec<(Nibbles, Bytes)> {
+        vec!["do", "dog", "doge", "horse"]
+            .into_iter()
+            .map(|key| {
+                (
+                    get_nibbles_from_bytes(key.as_bytes().to_vec()),
+                    convert_h256_to_bytes(keccak_hash_bytes(key.as_bytes()).unwrap()),
+                )
+            })
+            .collect()
+    }
+
+    fn get_trie_without_deleted_key_value_tuples(
+        key_value_tuples: &[(Nibbles, Bytes)],
+        indices_to_delete: &[usize],
+    ) -> Trie {
+        let mut trie =
+            put_in_trie_recursively(Trie::get_new_trie().unwrap(), key_value_tuples.to_vec(), 0)
+                .unwrap();
+        indices_to_delete
+            .iter()
+            .for_each(|i| trie.delete(key_value_tuples[*i].0.clone()).unwrap());
+        trie
+    }
+
+    #[test]
+    fn should_put_keys_prefixing_other_keys_in_trie_correctly() {
+        let expected_root = "0x896ea020925ef5bc25be8d0ace624a36676f6db2f656c66c03f1fafce54a96f0";
+        let key_value_tuples = get_prefix_key_value_tuples();
+        let trie =
+            put_in_trie_recursively(Trie::get_new_trie().unwrap(), key_value_tuples.clone(), 0)
+                .unwrap();
+        assert!(convert_h256_to_prefixed_hex(trie.root).unwrap() == expected_root);
+        key_value_tuples.into_iter().for_each(|(key, value)| {
+            assert!(trie.get(key).unwrap() == Some(value));
+        });
+    }
+
+    #[test]
+    fn should_delete_only_value_in_trie() {
+        let key = convert_hex_string_to_nibbles("c0ffe".to_string()).unwrap();
+        let mut trie = Trie::get_new_trie().unwrap();
+        trie.put(key.clone(), vec![0xde, 0xca, 0xff]).unwrap();
+        trie.delete(key).unwrap();
+        assert!(trie.root == HASHED_NULL_NODE);
+        assert!(trie.database.nodes.is_empty());
+    }
+
+    #[test]
+    fn should_not_change_trie_when_deleting_key_not_in_it() {
+        let key_value_tuples = get_prefix_key_value_tuples();
+        let mut trie =
+            put_in_trie_recursively(Trie::get_new_trie().unwrap(), key_value_tuples, 0).unwrap();
+        let expected_root = trie.root;
+        let expected_database = trie.database.clone();
+        vec!["d", "dogs", "horses", "cat"]
+            .into_iter()
+            .for_each(|key| {
+                trie.delete(get_nibbles_from_bytes(key.as_bytes().to_vec()))
+                    .unwrap()
+            });
+        assert!(trie.root == expected_root);
+        assert!(trie.database == expected_database);
+    }
+
+    #[test]
+    fn should_delete_from_trie_with_keys_prefixing_other_keys_correctly() {
+        let key_value_tuples = get_prefix_key_value_tuples();
+        vec![vec![0], vec![1], vec![2], vec![3], vec![0, 2], vec![1, 3]]
+            .into_iter()
+            .for_each(|indices_to_delete| {
+                let remaining_key_value_tuples = key_value_tuples
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| !indices_to_delete.contains(i))
+                    .map(|(_, key_value_tuple)| key_value_tuple.clone())
+                    .collect::<Vec<(Nibbles, Bytes)>>();
+                let expected_trie = put_in_trie_recursively(
+                    Trie::get_new_trie().unwrap(),
+                    remaining_key_value_tuples,
+                    0,
+                )
+                .unwrap();
+                let trie = get_trie_without_deleted_key_value_tuples(
+                    &key_value_tuples,
+                    &indices_to_delete,
+                );
+                assert!(trie.root == expected_trie.root);
+                assert!(trie.database == expected_trie.database);
+                key_value_tuples
+                    .iter()
+                    .enumerate()
+                    .for_each(|(i, (key, value))| {
+                        let result = trie.get(key.clone()).unwrap();
+                        match indices_to_delete.contains(&i) {
+                            true => assert!(result.is_none()),
+                            false => assert!(result == Some(value.clone())),
+                        }
+                    });
+            });
+    }
+
+    fn put_and_delete_in_trie<D: NodeStore>(
+        mut trie: Trie<D>,
+        key_maybe_value_strs: &[(&str, Option<&str>)],
+    ) -> Trie<D> {
+        key_maybe_value_strs.iter().for_each(|(key, maybe_value)| {
+            let key = get_nibbles_from_bytes(key.as_bytes().to_vec());
+            match maybe_value {
+                Some(value) => trie.put(key, value.as_bytes().to_vec()).unwrap(),
+                None => trie.delete(key).unwrap(),
+            }
+        });
+        trie
+    }
+
+    fn get_ethereum_tests_empty_values_sequence() -> Vec<(&'static str, Option<&'static str>)> {
+        // NOTE: The `emptyValues` test of ethereum/tests' TrieTests, where a null value deletes.
+        vec![
+            ("do", Some("verb")),
+            ("ether", Some("wookiedoo")),
+            ("horse", Some("stallion")),
+            ("shaman", Some("horse")),
+            ("doge", Some("coin")),
+            ("ether", None),
+            ("dog", Some("puppy")),
+            ("shaman", None),
+        ]
+    }
+
+    #[test]
+    fn should_put_and_delete_ethereum_tests_empty_values_vector_correctly() {
+        // NOTE: From ethereum/tests' TrieTests/trietest.json
+        let expected_root = "0x5991bb8c6514148a29db676a14ac506cd2cd5775ace63c30a4fe457715e9ac84";
+        let trie = put_and_delete_in_trie(
+            Trie::get_new_trie().unwrap(),
+            &get_ethereum_tests_empty_values_sequence(),
+        );
+        assert!(convert_h256_to_prefixed_hex(trie.root).unwrap() == expected_root);
+        vec!["ether", "shaman"].into_iter().for_each(|key| {
+            assert!(trie
+                .get(get_nibbles_from_bytes(key.as_bytes().to_vec()))
+                .unwrap()
+                .is_none())
+        });
+    }
+
+    #[test]
+    fn should_put_and_delete_ethereum_tests_secure_empty_values_vector_correctly() {
+        // NOTE: From ethereum/tests' TrieTests/trietest_secureTrie.json
+        let expected_root = "0x29b235a58c3c25ab83010c327d5932bcf05324b7d6b1185e650798034783ca9d";
+        let trie = put_and_delete_in_trie(
+            Trie::get_new_secure_trie().unwrap(),
+            &get_ethereum_tests_empty_values_sequence(),
+        );
+        assert!(convert_h256_to_prefixed_hex(trie.root).unwrap() == expected_root);
+    }
+
+    #[test]
+    fn should_get_receipts_root_after_interleaved_puts_and_deletes() {
+        let (expected_trie, key_value_tuples) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let num_receipts = key_value_tuples.len();
+        let get_decoy_key = |i: usize| convert_usize_index_to_trie_key(num_receipts + i).unwrap();
+        let mut trie = Trie::get_new_trie().unwrap();
+        key_value_tuples
+            .iter()
+            .enumerate()
+            .for_each(|(i, (key, value))| {
+                trie.put(get_decoy_key(i), get_sample_indexed_value(i))
+                    .unwrap();
+                trie.put(key.clone(), get_sample_indexed_value(i)).unwrap();
+                if i > 0 {
+                    trie.delete(get_decoy_key(i - 1)).unwrap();
+                    trie.delete(key_value_tuples[i - 1].0.clone()).unwrap();
+                    trie.put(
+                        key_value_tuples[i - 1].0.clone(),
+                        key_value_tuples[i - 1].1.clone(),
+                    )
+                    .unwrap();
+                };
+                trie.put(key.clone(), value.clone()).unwrap();
+            });
+        trie.delete(get_decoy_key(num_receipts - 1)).unwrap();
+        assert!(convert_h256_to_prefixed_hex(trie.root).unwrap() == RECEIPTS_ROOT_1);
+        assert!(trie.database == expected_trie.database);
+    }
+
+    #[test]
+    fn should_delete_receipts_from_trie_leaving_same_root_as_never_putting_them() {
+        let (_, key_value_tuples) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let indices_to_delete = (0..key_value_tuples.len())
+            .filter(|i| i % 3 != 0)
+            .collect::<Vec<usize>>();
+        let remaining_key_value_tuples = key_value_tuples
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !indices_to_delete.contains(i))
+            .map(|(_, key_value_tuple)| key_value_tuple.clone())
+            .collect::<Vec<(Nibbles, Bytes)>>();
+        let expected_trie =
+            put_in_trie_recursively(Trie::get_new_trie().unwrap(), remaining_key_value_tuples, 0)
+                .unwrap();
+        let result =
+            get_trie_without_deleted_key_value_tuples(&key_value_tuples, &indices_to_delete);
+        assert!(result.root == expected_trie.root);
+        assert!(result.database == expected_trie.database);
+    }
+
+    #[test]
+    fn should_leave_empty_trie_after_deleting_every_receipt() {
+        let (_, key_value_tuples) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let indices_to_delete = (0..key_value_tuples.len()).rev().collect::<Vec<usize>>();
+        let result =
+            get_trie_without_deleted_key_value_tuples(&key_value_tuples, &indices_to_delete);
+        assert!(result.root == HASHED_NULL_NODE);
+        assert!(result.database.nodes.is_empty());
+    }
+
+    #[test]
+    fn should_delete_from_secure_trie_by_unhashed_key() {
+        let key_value_tuples = get_sample_storage_key_value_tuples();
+        let expected_trie = put_in_trie_recursively(
+            Trie::get_new_secure_trie().unwrap(),
+            key_value_tuples[1..].to_vec(),
+            0,
+        )
+        .unwrap();
+        let mut trie = put_in_trie_recursively(
+            Trie::get_new_secure_trie().unwrap(),
+            key_value_tuples.clone(),
+            0,
+        )
+        .unwrap();
+        trie.delete(key_value_tuples[0].0.clone()).unwrap();
+        assert!(trie.root == expected_trie.root);
+    }
+
+    fn get_key_value_tuples_from_strs(key_value_strs: &[(&str, &str)]) -> Vec<(Nibbles, Bytes)> {
+        key_value_strs
+            .iter()
+            .map(|(key, value)| {
+                (
+                    get_nibbles_from_bytes(key.as_bytes().to_vec()),
+                    value.as_bytes().to_vec(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_get_reference_roots_of_tries_with_inline_nodes() {
+        vec![
+            (
+                vec![
+                    ("doe", "reindeer"),
+                    ("dog", "puppy"),
+                    ("dogglesworth", "cat"),
+                ],
+                "0x8aad789dff2f538bca5d8ea56e8abe10f4c7ba3a5dea95fea4cd6e7c3a1168d3",
+            ),
+            (
+                vec![("foo", "bar"), ("food", "bass")],
+                "0x17beaa1648bafa633cda809c90c04af50fc8aed3cb40d16efbddee6fdf63c4c3",
+            ),
+            (
+                vec![("a", "b")],
+                "0x09ca68268104f67d9da9c8514ebdd8c98c6667aba87016f8602a1fbefb575216",
+            ),
+        ]
+        .into_iter()
+        .for_each(|(key_value_strs, expected_root)| {
+            let key_value_tuples = get_key_value_tuples_from_strs(&key_value_strs);
+            let trie =
+                put_in_trie_recursively(Trie::get_new_trie().unwrap(), key_value_tuples.clone(), 0)
+                    .unwrap();
+            assert!(convert_h256_to_prefixed_hex(trie.root).unwrap() == expected_root);
+            key_value_tuples.into_iter().for_each(|(key, value)| {
+                assert!(trie.get(key).unwrap() == Some(value));
+            });
+        });
+    }
+
+    #[test]
+    fn should_only_save_hashed_nodes_and_root_in_trie_with_inline_nodes() {
+        let trie = get_sample_trie_with_inline_nodes();
+        assert!(convert_h256_to_prefixed_hex(trie.root).unwrap() == INLINE_TRIE_ROOT);
+        assert!(trie.database.nodes.contains_key(&trie.root));
+        trie.database.nodes.iter().for_each(|(hash, encoded_node)| {
+            assert!(encoded_node.len() >= HASH_LENGTH || *hash == trie.root);
+            assert!(keccak_hash_bytes(encoded_node).unwrap() == *hash);
+        });
+        get_sample_inline_key_value_tuples()
+            .into_iter()
+            .for_each(|(key, _)| {
+                let (_, node_stack, _) = trie.find(key).unwrap();
+                node_stack
+                    .iter()
+                    .filter(|node| node.is_inline().unwrap())
+                    .for_each(|node| {
+                        assert!(!trie.database.nodes.contains_key(&node.get_hash().unwrap()))
+                    });
+            });
+    }
+
+    #[test]
+    fn should_delete_from_trie_with_inline_nodes_correctly() {
+        vec![
+            (
+                "doge",
+                "0x40b4a841a5ed78d2beb33a3dbba6dd38f5b1566db97ae643e073ded3aa77dceb",
+            ),
+            (
+                "dogglesworth",
+                "0x05ae693aac2107336a79309e0c60b24a7aac6aa3edecaef593921500d33c63c4",
+            ),
+        ]
+        .into_iter()
+        .for_each(|(key_to_delete, expected_root)| {
+            let key_value_tuples = match key_to_delete {
+                "doge" => get_sample_inline_key_value_tuples(),
+                _ => get_key_value_tuples_from_strs(&[
+                    ("doe", "reindeer"),
+                    ("dog", "puppy"),
+                    ("dogglesworth", "cat"),
+                ]),
+            };
+            let key = get_nibbles_from_bytes(key_to_delete.as_bytes().to_vec());
+            let remaining_key_value_tuples = key_value_tuples
+                .iter()
+                .filter(|(k, _)| *k != key)
+                .cloned()
+                .collect::<Vec<(Nibbles, Bytes)>>();
+            let expected_trie = put_in_trie_recursively(
+                Trie::get_new_trie().unwrap(),
+                remaining_key_value_tuples,
+                0,
+            )
+            .unwrap();
+            let mut trie =
+                put_in_trie_recursively(Trie::get_new_trie().unwrap(), key_value_tuples, 0)
+                    .unwrap();
+            trie.delete(key.clone()).unwrap();
+            assert!(convert_h256_to_prefixed_hex(trie.root).unwrap() == expected_root);
+            assert!(trie.database == expected_trie.database);
+            assert!(trie.get(key).unwrap().is_none());
+        });
+    }
+
+    fn get_sample_receipts_1_trie_in_file_store(
+        directory: &std::path::Path,
+    ) -> Trie<FileNodeStore> {
+        let (_, key_value_tuples) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let store = FileNodeStore::new(directory).unwrap();
+        put_in_trie_recursively(
+            Trie::get_new_trie_in_store(store).unwrap(),
+            key_value_tuples,
+            0,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn should_build_same_trie_in_file_store_as_in_memory() {
+        let directory = get_temporary_directory("trie-in-file-store");
+        let (expected_trie, _) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let trie = get_sample_receipts_1_trie_in_file_store(&directory);
+        assert!(convert_h256_to_prefixed_hex(trie.root).unwrap() == RECEIPTS_ROOT_1);
+        assert!(fs::read_dir(&directory).unwrap().count() == expected_trie.database.nodes.len());
+        expected_trie
+            .database
+            .nodes
+            .iter()
+            .for_each(|(hash, encoded_node)| {
+                assert!(trie.database.get(hash).unwrap() == Some(encoded_node.clone()))
+            });
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_reopen_trie_at_root_in_file_store() {
+        let directory = get_temporary_directory("reopen-trie-in-file-store");
+        let root = get_sample_receipts_1_trie_in_file_store(&directory).root;
+        let (_, key_value_tuples) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let store = FileNodeStore::new(&directory).unwrap();
+        let trie = Trie::open_trie_at_root(root, store).unwrap();
+        key_value_tuples.into_iter().for_each(|(key, value)| {
+            assert!(trie.get(key).unwrap() == Some(value));
+        });
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_empty_file_store_when_deleting_every_key_from_trie() {
+        let directory = get_temporary_directory("delete-from-file-store");
+        let mut trie = get_sample_receipts_1_trie_in_file_store(&directory);
+        let (_, key_value_tuples) = get_sample_receipts_1_trie_and_key_value_tuples();
+        key_value_tuples
+            .into_iter()
+            .for_each(|(key, _)| trie.delete(key).unwrap());
+        assert!(trie.root == HASHED_NULL_NODE);
+        assert!(fs::read_dir(&directory).unwrap().count() == 0);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_open_empty_trie_at_null_root() {
+        let trie = Trie::open_trie_at_root(HASHED_NULL_NODE, get_new_database().unwrap()).unwrap();
+        assert!(trie
+            .get(convert_usize_index_to_trie_key(0).unwrap())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn should_fail_to_open_trie_at_root_not_in_store() {
+        let (trie, _) = get_sample_receipts_1_trie_and_key_value_tuples();
+        match Trie::open_trie_at_root(trie.root, get_new_database().unwrap()) {
+            Err(AppError::MissingNodeError(hash)) => assert!(hash == trie.root),
+            _ => panic!("Should not open trie at root not in store!"),
+        }
+    }
+
+    fn get_duplicate_leaf_key_value_tuples() -> Vec<(Nibbles, Bytes)> {
+        vec![
+            (
+                convert_hex_string_to_nibbles("0123".to_string()).unwrap(),
+                get_sample_indexed_value(0),
+            ),
+            (
+                convert_hex_string_to_nibbles("1123".to_string()).unwrap(),
+                get_sample_indexed_value(0),
+            ),
+            (
+                convert_hex_string_to_nibbles("2123".to_string()).unwrap(),
+                get_sample_indexed_value(1),
+            ),
+        ]
+    }
+
+    fn get_duplicate_leaf_hash() -> H256 {
+        let path = convert_hex_string_to_nibbles("123".to_string()).unwrap();
+        Node::get_new_leaf_node(path, get_sample_indexed_value(0))
+            .unwrap()
+            .get_hash()
+            .unwrap()
+    }
+
+    #[test]
+    fn should_count_both_references_to_duplicate_leaf() {
+        let trie = put_in_trie_recursively(
+            Trie::get_new_trie().unwrap(),
+            get_duplicate_leaf_key_value_tuples(),
+            0,
+        )
+        .unwrap();
+        assert!(
+            trie.database
+                .get_reference_count(&get_duplicate_leaf_hash())
+                .unwrap()
+                == 2
+        );
+    }
+
+    #[test]
+    fn should_keep_duplicate_leaf_when_deleting_one_of_its_keys() {
+        let key_value_tuples = get_duplicate_leaf_key_value_tuples();
+        let expected_trie = get_trie_without_deleted_key_value_tuples(&key_value_tuples[1..], &[]);
+        let trie = get_trie_without_deleted_key_value_tuples(&key_value_tuples, &[0]);
+        assert!(
+            trie.get(key_value_tuples[1].0.clone()).unwrap() == Some(key_value_tuples[1].1.clone())
+        );
+        assert!(
+            trie.database
+                .get_reference_count(&get_duplicate_leaf_hash())
+                .unwrap()
+                == 1
+        );
+        assert!(trie.root == expected_trie.root);
+        assert!(trie.database == expected_trie.database);
+    }
+
+    #[test]
+    fn should_keep_duplicate_leaf_when_updating_one_of_its_keys() {
+        let mut key_value_tuples = get_duplicate_leaf_key_value_tuples();
+        let mut trie =
+            put_in_trie_recursively(Trie::get_new_trie().unwrap(), key_value_tuples.clone(), 0)
+                .unwrap();
+        key_value_tuples[0].1 = get_sample_indexed_value(1);
+        trie.put(key_value_tuples[0].0.clone(), key_value_tuples[0].1.clone())
+            .unwrap();
+        let expected_trie = get_trie_without_deleted_key_value_tuples(&key_value_tuples, &[]);
+        assert!(
+            trie.get(key_value_tuples[1].0.clone()).unwrap() == Some(key_value_tuples[1].1.clone())
+        );
+        assert!(trie.root == expected_trie.root);
+        assert!(trie.database == expected_trie.database);
+    }
+
+    #[test]
+    fn should_delete_half_of_trie_of_identical_values_correctly() {
+        let key_value_tuples = (0..300)
+            .map(|i| {
+                (
+                    convert_usize_index_to_trie_key(i).unwrap(),
+                    get_sample_indexed_value(0),
+                )
+            })
+            .collect::<Vec<(Nibbles, Bytes)>>();
+        let indices_to_delete = (0..300).step_by(2).collect::<Vec<usize>>();
+        let remaining_key_value_tuples = key_value_tuples
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .cloned()
+            .collect::<Vec<(Nibbles, Bytes)>>();
+        let expected_trie =
+            get_trie_without_deleted_key_value_tuples(&remaining_key_value_tuples, &[]);
+        let trie = get_trie_without_deleted_key_value_tuples(&key_value_tuples, &indices_to_delete);
+        remaining_key_value_tuples
+            .into_iter()
+            .for_each(|(key, value)| {
+                assert!(trie.get(key).unwrap() == Some(value));
+            });
+        assert!(trie.root == expected_trie.root);
+        assert!(trie.database == expected_trie.database);
+    }
+
+    fn get_sample_receipts_1_partial_trie(indices: &[usize]) -> Trie {
+        let (trie, _) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let proof_nodes =
+            get_rlp_encoded_nodes_from_node_stack(&get_branches_from_trie(&trie, indices).unwrap());
+        Trie::get_partial_trie_from_proof_nodes(trie.root, &proof_nodes).unwrap()
+    }
+
+    #[test]
+    fn should_get_and_find_covered_keys_in_partial_trie() {
+        let indices = [3, 14];
+        let (trie, _) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let partial_trie = get_sample_receipts_1_partial_trie(&indices);
+        indices.iter().for_each(|index| {
+            let key = convert_usize_index_to_trie_key(*index).unwrap();
+            assert!(partial_trie.get(key.clone()).unwrap() == trie.get(key.clone()).unwrap());
+            assert!(partial_trie.find(key.clone()).unwrap() == trie.find(key).unwrap());
+        });
+    }
+
+    #[test]
+    fn should_get_missing_node_error_for_uncovered_key_in_partial_trie() {
+        let (trie, _) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let partial_trie = get_sample_receipts_1_partial_trie(&[14]);
+        let key = convert_usize_index_to_trie_key(3).unwrap();
+        match partial_trie.get(key.clone()) {
+            Err(AppError::MissingNodeError(hash)) => {
+                assert!(trie.database.contains(&hash).unwrap());
+                assert!(!partial_trie.database.contains(&hash).unwrap());
+            }
+            _ => panic!("Should get missing node error for uncovered key!"),
+        }
+        match partial_trie.find(key) {
+            Err(AppError::MissingNodeError(_)) => (),
+            _ => panic!("Should get missing node error for uncovered key!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_get_partial_trie_without_root_node() {
+        let (trie, _) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let mut proof_nodes =
+            get_rlp_encoded_nodes_from_node_stack(&get_branch_from_trie(&trie, 14).unwrap());
+        proof_nodes.remove(0);
+        match Trie::get_partial_trie_from_proof_nodes(trie.root, &proof_nodes) {
+            Err(AppError::MissingNodeError(hash)) => assert!(hash == trie.root),
+            _ => panic!("Should not get partial trie without root node!"),
+        }
+    }
+
+    #[test]
+    fn should_get_same_root_updating_covered_keys_in_partial_trie() {
+        let indices = [3, 14, 20];
+        let (mut trie, _) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let mut partial_trie = get_sample_receipts_1_partial_trie(&indices);
+        indices.iter().for_each(|index| {
+            let key = convert_usize_index_to_trie_key(*index).unwrap();
+            trie.put(key.clone(), get_sample_indexed_value(*index))
+                .unwrap();
+            partial_trie
+                .put(key, get_sample_indexed_value(*index))
+                .unwrap();
+        });
+        assert!(partial_trie.root != get_sample_receipts_1_partial_trie(&indices).root);
+        assert!(partial_trie.root == trie.root);
+    }
+
+    #[test]
+    fn should_get_same_root_putting_excluded_key_in_partial_trie() {
+        let (mut trie, key_value_tuples) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let index = key_value_tuples.len();
+        let key = convert_usize_index_to_trie_key(index).unwrap();
+        let proof_nodes = get_rlp_encoded_nodes_from_node_stack(
+            &get_exclusion_branch_from_trie(&trie, index).unwrap(),
+        );
+        let mut partial_trie =
+            Trie::get_partial_trie_from_proof_nodes(trie.root, &proof_nodes).unwrap();
+        assert!(partial_trie.get(key.clone()).unwrap().is_none());
+        trie.put(key.clone(), get_sample_indexed_value(index))
+            .unwrap();
+        partial_trie
+            .put(key, get_sample_indexed_value(index))
+            .unwrap();
+        assert!(partial_trie.root == trie.root);
+    }
+
+    #[test]
+    fn should_get_value_from_partial_secure_trie_seeded_from_storage_proof() {
+        let account_proof = get_sample_account_proof();
+        let storage_proof = &account_proof.storage_proof[2];
+        let partial_trie = Trie::get_partial_secure_trie_from_proof_nodes(
+            account_proof.account.storage_root,
+            &storage_proof.proof,
+        )
+        .unwrap();
+        let key = get_nibbles_from_bytes(convert_h256_to_bytes(storage_proof.key));
+        let result = partial_trie.get(key).unwrap();
+        assert!(result == Some(rlp::encode(&storage_proof.value)));
+    }
 }
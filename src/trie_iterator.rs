@@ -0,0 +1,261 @@
+use crate::constants::HASHED_NULL_NODE;
+use crate::nibble_utils::{get_nibble_bytes_from_nibbles, get_nibbles_from_nibble_bytes, Nibbles};
+use crate::node_store::NodeStore;
+use crate::trie::Trie;
+use crate::trie_nodes::{get_existing_node_from_reference, Node};
+use crate::types::{Byte, Bytes, Result};
+
+/**
+ *
+ * The Trie Iterator:
+ *
+ * Walks a trie depth first, yielding each key & value it holds in key order,
+ * from the optional start key onwards. Nodes are resolved lazily as the walk
+ * reaches them, hashed ones via `get_node_from_database` & inline ones from
+ * their parents, so only a trie's nodes from the start key onwards are read.
+ *
+ * Each pending node on the stack is paired with the path of nibbles leading
+ * to it. A branch's own value has the shortest key of any beneath it, so is
+ * yielded first, with its children then pushed in reverse so the lowest is
+ * walked next. Subtrees whose paths sort wholly before the start key are
+ * never pushed at all.
+ *
+ * A node missing from the store, as in a partial trie, is yielded as an error
+ * & ends the iteration. Keys of secure tries are yielded as the hashed paths
+ * the values are actually stored under.
+ *
+ */
+pub struct TrieIterator<'a, D: NodeStore> {
+    trie: &'a Trie<D>,
+    start_key: Bytes,
+    stack: Vec<(Bytes, Bytes)>,
+}
+
+impl<'a, D: NodeStore> TrieIterator<'a, D> {
+    fn is_before_start_key(&self, path: &[Byte]) -> bool {
+        path < &self.start_key[..path.len().min(self.start_key.len())]
+    }
+
+    fn push_child_reference(&mut self, path: Bytes, reference: Bytes) {
+        if !self.is_before_start_key(&path) {
+            self.stack.push((path, reference))
+        }
+    }
+
+    fn get_key_value_if_not_before_start(
+        &self,
+        path: Bytes,
+        value: Bytes,
+    ) -> Option<Result<(Nibbles, Bytes)>> {
+        match path < self.start_key {
+            true => None,
+            false => Some(Ok((get_nibbles_from_nibble_bytes(path), value))),
+        }
+    }
+}
+
+impl<'a, D: NodeStore> Iterator for TrieIterator<'a, D> {
+    type Item = Result<(Nibbles, Bytes)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((path, reference)) = self.stack.pop() {
+            let node = match get_existing_node_from_reference(&self.trie.database, &reference) {
+                Ok(node) => node,
+                Err(e) => {
+                    self.stack.clear();
+                    return Some(Err(e));
+                }
+            };
+            let key_value = match node {
+                Node::Null => None,
+                Node::Leaf(leaf) => match get_nibble_bytes_from_nibbles(&leaf.path_nibbles) {
+                    Ok(leaf_path) => self
+                        .get_key_value_if_not_before_start([path, leaf_path].concat(), leaf.value),
+                    Err(e) => Some(Err(e)),
+                },
+                Node::Extension(extension) => {
+                    match get_nibble_bytes_from_nibbles(&extension.path_nibbles) {
+                        Ok(extension_path) => {
+                            self.push_child_reference(
+                                [path, extension_path].concat(),
+                                extension.value,
+                            );
+                            None
+                        }
+                        Err(e) => Some(Err(e)),
+                    }
+                }
+                Node::Branch(branch) => {
+                    branch
+                        .branches
+                        .iter()
+                        .enumerate()
+                        .rev()
+                        .for_each(|(i, child)| {
+                            if let Some(reference) = child {
+                                self.push_child_reference(
+                                    [path.clone(), vec![i as Byte]].concat(),
+                                    reference.clone(),
+                                )
+                            }
+                        });
+                    branch
+                        .value
+                        .and_then(|value| self.get_key_value_if_not_before_start(path, value))
+                }
+            };
+            if key_value.is_some() {
+                return key_value;
+            };
+        }
+        None
+    }
+}
+
+pub fn get_trie_iterator<D: NodeStore>(
+    trie: &Trie<D>,
+    start_key: Option<Nibbles>,
+) -> Result<TrieIterator<'_, D>> {
+    Ok(TrieIterator {
+        trie,
+        start_key: match start_key {
+            Some(key) => get_nibble_bytes_from_nibbles(&key)?,
+            None => Vec::new(),
+        },
+        stack: match trie.root == HASHED_NULL_NODE {
+            true => Vec::new(),
+            false => vec![(Vec::new(), trie.root.as_bytes().to_vec())],
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::AppError;
+    use crate::get_branch_from_trie::{convert_usize_index_to_trie_key, get_branch_from_trie};
+    use crate::nibble_utils::get_nibbles_from_offset_bytes;
+    use crate::proof_recorder::get_recording_trie;
+    use crate::test_utils::{
+        get_rlp_encoded_nodes_from_node_stack, get_sample_inline_key_value_tuples,
+        get_sample_receipts_1_trie_and_key_value_tuples, get_sample_trie_with_indexed_values,
+        get_sample_trie_with_inline_nodes,
+    };
+
+    fn get_key_value_tuples_in_key_order(
+        mut key_value_tuples: Vec<(Nibbles, Bytes)>,
+    ) -> Vec<(Nibbles, Bytes)> {
+        key_value_tuples.sort_by_key(|(key, _)| get_nibble_bytes_from_nibbles(key).unwrap());
+        key_value_tuples
+    }
+
+    fn collect_key_value_tuples<D: NodeStore>(
+        trie: &Trie<D>,
+        start_key: Option<Nibbles>,
+    ) -> Vec<(Nibbles, Bytes)> {
+        get_trie_iterator(trie, start_key)
+            .unwrap()
+            .collect::<Result<Vec<(Nibbles, Bytes)>>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn should_iterate_over_nothing_in_empty_trie() {
+        let trie = Trie::get_new_trie().unwrap();
+        assert!(get_trie_iterator(&trie, None).unwrap().next().is_none());
+    }
+
+    #[test]
+    fn should_iterate_over_sample_receipts_trie_in_key_order() {
+        let (trie, key_value_tuples) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let expected_result = get_key_value_tuples_in_key_order(key_value_tuples);
+        let result = collect_key_value_tuples(&trie, None);
+        assert!(result == expected_result);
+        assert!(result.last().unwrap().0 == convert_usize_index_to_trie_key(0).unwrap());
+    }
+
+    #[test]
+    fn should_iterate_over_keys_prefixing_other_keys_in_key_order() {
+        let trie = get_sample_trie_with_inline_nodes();
+        let expected_result =
+            get_key_value_tuples_in_key_order(get_sample_inline_key_value_tuples());
+        let result = collect_key_value_tuples(&trie, None);
+        assert!(result == expected_result);
+    }
+
+    #[test]
+    fn should_iterate_from_start_key_in_trie() {
+        let key_value_tuples =
+            get_key_value_tuples_in_key_order(get_sample_inline_key_value_tuples());
+        let trie = get_sample_trie_with_inline_nodes();
+        let start_key = key_value_tuples[1].0.clone();
+        let result = collect_key_value_tuples(&trie, Some(start_key));
+        assert!(result == key_value_tuples[1..].to_vec());
+    }
+
+    #[test]
+    fn should_iterate_from_start_key_not_in_trie() {
+        let (trie, key_value_tuples) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let start_key = convert_usize_index_to_trie_key(key_value_tuples.len()).unwrap();
+        let expected_result = vec![key_value_tuples[0].clone()];
+        let result = collect_key_value_tuples(&trie, Some(start_key));
+        assert!(result == expected_result);
+    }
+
+    #[test]
+    fn should_iterate_from_odd_length_start_key() {
+        let (trie, key_value_tuples) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let key_value_tuples = get_key_value_tuples_in_key_order(key_value_tuples);
+        let start_key = get_nibbles_from_offset_bytes(vec![0x01]);
+        let expected_result = key_value_tuples
+            .into_iter()
+            .filter(|(key, _)| key.data[0] >= 0x10)
+            .collect::<Vec<(Nibbles, Bytes)>>();
+        let result = collect_key_value_tuples(&trie, Some(start_key));
+        assert!(!expected_result.is_empty());
+        assert!(result == expected_result);
+    }
+
+    #[test]
+    fn should_find_no_index_missing_from_trie_of_indexed_values() {
+        let num_values = 1000;
+        let trie = get_sample_trie_with_indexed_values(num_values);
+        let mut result_keys = collect_key_value_tuples(&trie, None)
+            .into_iter()
+            .map(|(key, _)| get_nibble_bytes_from_nibbles(&key).unwrap())
+            .collect::<Vec<Bytes>>();
+        let mut expected_keys = (0..num_values)
+            .map(|index| {
+                get_nibble_bytes_from_nibbles(&convert_usize_index_to_trie_key(index).unwrap())
+                    .unwrap()
+            })
+            .collect::<Vec<Bytes>>();
+        expected_keys.sort();
+        assert!(result_keys == expected_keys);
+        result_keys.dedup();
+        assert!(result_keys.len() == num_values);
+    }
+
+    #[test]
+    fn should_yield_missing_node_error_then_stop_in_partial_trie() {
+        let (trie, _) = get_sample_receipts_1_trie_and_key_value_tuples();
+        let proof_nodes =
+            get_rlp_encoded_nodes_from_node_stack(&get_branch_from_trie(&trie, 14).unwrap());
+        let partial_trie =
+            Trie::get_partial_trie_from_proof_nodes(trie.root, &proof_nodes).unwrap();
+        let mut iterator = get_trie_iterator(&partial_trie, None).unwrap();
+        match iterator.next() {
+            Some(Err(AppError::MissingNodeError(_))) => (),
+            _ => panic!("Should yield missing node error in partial trie!"),
+        }
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    fn should_record_every_node_when_iterating_recording_trie() {
+        let trie = get_recording_trie(get_sample_trie_with_indexed_values(100)).unwrap();
+        let num_nodes = trie.database.store.nodes.len();
+        assert!(collect_key_value_tuples(&trie, None).len() == 100);
+        assert!(trie.database.get_witness().len() == num_nodes);
+    }
+}
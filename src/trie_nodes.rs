@@ -1,25 +1,28 @@
 use crate::constants::{
-    BRANCH_NODE_STRING, EMPTY_NIBBLES, EXTENSION_NODE_STRING, LEAF_NODE_STRING,
+    BRANCH_NODE_STRING, EMPTY_NIBBLES, EXTENSION_NODE_STRING, HASH_LENGTH, LEAF_NODE_STRING,
+    NULL_NODE_STRING,
 };
 use crate::errors::AppError;
 use crate::get_database::get_thing_from_database;
 use crate::get_keccak_hash::keccak_hash_bytes;
 use crate::nibble_utils::Nibbles;
+use crate::node_store::NodeStore;
 use crate::path_codec::{
     decode_path_to_nibbles_and_node_type, encode_extension_path_from_nibbles,
     encode_leaf_path_from_nibbles,
 };
-use crate::types::{Bytes, ChildNodes, Database, Result};
+use crate::types::{Byte, Bytes, ChildNodes, Result};
+use crate::utils::{convert_bytes_to_h256, convert_h256_to_bytes};
 use ethereum_types::H256;
 use rlp::{Rlp, RlpStream};
 
-static NO_NODE_IN_STRUCT_ERR: &str = "✘ No node present in struct to rlp-encode!";
-
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub struct Node {
-    pub leaf: Option<LeafNode>,
-    pub branch: Option<BranchNode>,
-    pub extension: Option<ExtensionNode>,
+pub enum Node {
+    Null,
+    Leaf(LeafNode),
+    Extension(ExtensionNode),
+    Branch(BranchNode),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -49,178 +52,237 @@ impl Node {
         let encoded_path = encode_leaf_path_from_nibbles(path_nibbles.clone())?;
         let mut raw = encoded_path.clone();
         raw.append(&mut value.clone());
-        Ok(Node {
-            branch: None,
-            extension: None,
-            leaf: Some(LeafNode {
-                raw,
-                value,
-                path_nibbles,
-                encoded_path,
-            }),
-        })
+        Ok(Node::Leaf(LeafNode {
+            raw,
+            value,
+            path_nibbles,
+            encoded_path,
+        }))
     }
 
     pub fn get_new_extension_node(path_nibbles: Nibbles, value: Bytes) -> Result<Node> {
         let encoded_path = encode_extension_path_from_nibbles(path_nibbles.clone())?;
         let mut raw = encoded_path.clone();
         raw.append(&mut value.clone());
-        Ok(Node {
-            leaf: None,
-            branch: None,
-            extension: Some(ExtensionNode {
-                raw,
-                value,
-                path_nibbles,
-                encoded_path,
-            }),
-        })
+        Ok(Node::Extension(ExtensionNode {
+            raw,
+            value,
+            path_nibbles,
+            encoded_path,
+        }))
     }
 
     pub fn get_new_branch_node(value: Option<Bytes>) -> Result<Node> {
-        Ok(Node {
-            leaf: None,
-            extension: None,
-            branch: Some(BranchNode {
-                value,
-                branches: get_empty_child_nodes(),
-            }),
-        })
+        Ok(Node::Branch(BranchNode {
+            value,
+            branches: get_empty_child_nodes(),
+        }))
     }
 
     pub fn update_branch_at_index(self, new_value: Option<Bytes>, index: usize) -> Result<Self> {
-        if let Some(branch) = self.branch {
-            Ok(Node {
-                leaf: None,
-                extension: None,
-                branch: Some(BranchNode {
-                    value: branch.value,
-                    branches: update_child_nodes(branch.branches, new_value, index)?,
-                }),
-            })
-        } else {
-            Err(AppError::Custom(
+        match self {
+            Node::Branch(branch) => Ok(Node::Branch(BranchNode {
+                value: branch.value,
+                branches: update_child_nodes(branch.branches, new_value, index)?,
+            })),
+            _ => Err(AppError::Custom(
                 "✘ Cannot update branches - not a branch node!".to_string(),
-            ))
+            )),
         }
     }
 
+    pub fn update_branch_value(self, new_value: Option<Bytes>) -> Result<Self> {
+        match self {
+            Node::Branch(branch) => Ok(Node::Branch(BranchNode {
+                value: new_value,
+                branches: branch.branches,
+            })),
+            _ => Err(AppError::Custom(
+                "✘ Cannot update value - not a branch node!".to_string(),
+            )),
+        }
+    }
+    /**
+     * The null node, which stands for an empty trie, encodes as the empty
+     * string, and so hashes to the `HASHED_NULL_NODE` of an empty trie's root.
+     *
+     */
     pub fn get_rlp_encoding(&self) -> Result<Bytes> {
         let mut rlp_stream = RlpStream::new();
-        if let Some(leaf) = &self.leaf {
-            rlp_stream.begin_list(2);
-            rlp_stream.append(&leaf.encoded_path);
-            rlp_stream.append(&leaf.value);
-            Ok(rlp_stream.out())
-        } else if let Some(extension) = &self.extension {
-            rlp_stream.begin_list(2);
-            rlp_stream.append(&extension.encoded_path);
-            rlp_stream.append(&extension.value);
-            Ok(rlp_stream.out())
-        } else if let Some(branch) = &self.branch {
-            rlp_stream.begin_list(17);
-            for i in 0..branch.branches.len() {
-                match &branch.branches[i] {
+        match self {
+            Node::Null => {
+                rlp_stream.append_empty_data();
+            }
+            Node::Leaf(leaf) => {
+                rlp_stream.begin_list(2);
+                rlp_stream.append(&leaf.encoded_path);
+                rlp_stream.append(&leaf.value);
+            }
+            Node::Extension(extension) => {
+                rlp_stream.begin_list(2);
+                rlp_stream.append(&extension.encoded_path);
+                append_node_reference_to_rlp_stream(&mut rlp_stream, &extension.value);
+            }
+            Node::Branch(branch) => {
+                rlp_stream.begin_list(17);
+                for child in branch.branches.iter() {
+                    match child {
+                        None => {
+                            rlp_stream.append_empty_data();
+                        }
+                        Some(reference) => {
+                            append_node_reference_to_rlp_stream(&mut rlp_stream, reference)
+                        }
+                    };
+                }
+                match &branch.value {
                     None => rlp_stream.append_empty_data(),
-                    Some(thing) => rlp_stream.append(&thing.clone()),
+                    Some(value) => rlp_stream.append(value),
                 };
             }
-            match &branch.value {
-                None => rlp_stream.append_empty_data(),
-                Some(value) => rlp_stream.append(&value.clone()),
-            };
-            Ok(rlp_stream.out())
-        } else {
-            Err(AppError::Custom(NO_NODE_IN_STRUCT_ERR.to_string()))
-        }
+        };
+        Ok(rlp_stream.out())
     }
 
     pub fn get_hash(&self) -> Result<H256> {
         self.get_rlp_encoding()
             .and_then(|encoded| keccak_hash_bytes(&encoded))
     }
+    /**
+     * A parent refers to a child by the keccak hash of the child's encoding,
+     * unless that encoding is shorter than a hash. Then the child is instead
+     * embedded inline in its parent, its encoding itself being the reference.
+     *
+     */
+    pub fn get_reference(&self) -> Result<Bytes> {
+        self.get_rlp_encoding()
+            .and_then(|encoded| match encoded.len() < HASH_LENGTH {
+                true => Ok(encoded),
+                false => keccak_hash_bytes(&encoded).map(convert_h256_to_bytes),
+            })
+    }
+
+    pub fn is_inline(&self) -> Result<bool> {
+        self.get_rlp_encoding()
+            .map(|encoded| encoded.len() < HASH_LENGTH)
+    }
 
     pub fn get_key(&self) -> Nibbles {
-        if let Some(leaf_node) = &self.leaf {
-            leaf_node.path_nibbles.clone()
-        } else if let Some(extension_node) = &self.extension {
-            // TODO/FIXME: Could be inline node!!
-            extension_node.path_nibbles.clone()
-        } else {
-            EMPTY_NIBBLES
+        match self {
+            Node::Leaf(leaf) => leaf.path_nibbles.clone(),
+            Node::Extension(extension) => extension.path_nibbles.clone(),
+            Node::Null | Node::Branch(_) => EMPTY_NIBBLES,
         }
     }
 
     pub fn get_key_length(&self) -> usize {
-        if let Some(leaf_node) = &self.leaf {
-            leaf_node.path_nibbles.clone().len()
-        } else if let Some(extension_node) = &self.extension {
-            // TODO/FIXME: Could be inline node!!
-            extension_node.path_nibbles.clone().len()
-        } else if self.branch.is_some() {
-            1
-        } else {
-            0
+        match self {
+            Node::Null => 0,
+            Node::Leaf(leaf) => leaf.path_nibbles.len(),
+            Node::Extension(extension) => extension.path_nibbles.len(),
+            Node::Branch(_) => 1,
         }
     }
 
     pub fn get_value(&self) -> Option<Bytes> {
-        if let Some(leaf_node) = &self.leaf {
-            Some(leaf_node.value.clone())
-        } else if let Some(extension_node) = &self.extension {
-            Some(extension_node.value.clone())
-        } else if let Some(branch_node) = &self.branch {
-            branch_node.value.clone()
-        } else {
-            None
+        match self {
+            Node::Null => None,
+            Node::Leaf(leaf) => Some(leaf.value.clone()),
+            Node::Extension(extension) => Some(extension.value.clone()),
+            Node::Branch(branch) => branch.value.clone(),
+        }
+    }
+
+    /**
+     * The references a node holds to its children, whether hashes or inline
+     * encodings. Leaves & the null node have none.
+     *
+     */
+    pub fn get_child_references(&self) -> Vec<Bytes> {
+        match self {
+            Node::Null | Node::Leaf(_) => Vec::new(),
+            Node::Extension(extension) => vec![extension.value.clone()],
+            Node::Branch(branch) => branch.branches.iter().flatten().cloned().collect(),
         }
     }
 
     pub fn get_type(&self) -> &'static str {
-        if self.leaf.is_some() {
-            LEAF_NODE_STRING
-        } else if self.branch.is_some() {
-            BRANCH_NODE_STRING
-        } else {
-            EXTENSION_NODE_STRING
+        match self {
+            Node::Null => NULL_NODE_STRING,
+            Node::Leaf(_) => LEAF_NODE_STRING,
+            Node::Extension(_) => EXTENSION_NODE_STRING,
+            Node::Branch(_) => BRANCH_NODE_STRING,
         }
     }
 }
 
+fn append_node_reference_to_rlp_stream(rlp_stream: &mut RlpStream, reference: &[Byte]) {
+    match is_inline_node_reference(reference) {
+        true => rlp_stream.append_raw(reference, 1),
+        false => rlp_stream.append(&reference),
+    };
+}
+
+fn decode_node_reference(rlp: &Rlp) -> Result<Bytes> {
+    match rlp.is_list() {
+        true => Ok(rlp.as_raw().to_vec()),
+        false => rlp
+            .data()
+            .map(|data| data.to_vec())
+            .map_err(|e| AppError::Custom(e.to_string())),
+    }
+}
+
+pub fn is_inline_node_reference(reference: &[Byte]) -> bool {
+    reference.len() < HASH_LENGTH
+}
+
 pub fn rlp_decode_node(rlp_data: Bytes) -> Result<Node> {
-    match Rlp::new(&rlp_data).as_list() {
+    let rlp = Rlp::new(&rlp_data);
+    let get_item = |i: usize| rlp.at(i).map_err(|e| AppError::Custom(e.to_string()));
+    if rlp.is_empty() && rlp.is_data() {
+        return Ok(Node::Null);
+    };
+    match rlp.item_count() {
         Err(e) => Err(AppError::Custom(e.to_string())),
-        Ok(list) => match list.len() {
+        Ok(num_items) => match num_items {
             2 => {
-                let path: &Bytes = &list[0];
-                let value: &Bytes = &list[1];
-                let (path_nibbles, node_type) = decode_path_to_nibbles_and_node_type(path.clone())?;
+                let path: Bytes = get_item(0)?
+                    .as_val()
+                    .map_err(|e| AppError::Custom(e.to_string()))?;
+                let (path_nibbles, node_type) = decode_path_to_nibbles_and_node_type(path)?;
                 match node_type == LEAF_NODE_STRING {
-                    true => Node::get_new_leaf_node(path_nibbles, value.to_vec()),
-                    false => Node::get_new_extension_node(path_nibbles, value.to_vec()),
+                    true => Node::get_new_leaf_node(
+                        path_nibbles,
+                        get_item(1)?
+                            .as_val()
+                            .map_err(|e| AppError::Custom(e.to_string()))?,
+                    ),
+                    false => Node::get_new_extension_node(
+                        path_nibbles,
+                        decode_node_reference(&get_item(1)?)?,
+                    ),
                 }
             }
             17 => {
-                let value: &Bytes = &list[16];
+                let value: Bytes = get_item(16)?
+                    .as_val()
+                    .map_err(|e| AppError::Custom(e.to_string()))?;
                 let mut branches = get_empty_child_nodes();
-                for i in 0..16 {
-                    if !list[i].is_empty() {
-                        let value: &Bytes = &list[i];
-                        branches[i] = Some(value.to_vec())
+                for (i, branch) in branches.iter_mut().enumerate() {
+                    let reference = decode_node_reference(&get_item(i)?)?;
+                    if !reference.is_empty() {
+                        *branch = Some(reference)
                     }
                 }
-                Ok(Node {
-                    leaf: None,
-                    extension: None,
-                    branch: Some(BranchNode {
-                        branches,
-                        value: if !value.is_empty() {
-                            Some(value.to_vec())
-                        } else {
-                            None
-                        },
-                    }),
-                })
+                Ok(Node::Branch(BranchNode {
+                    branches,
+                    value: if !value.is_empty() {
+                        Some(value.to_vec())
+                    } else {
+                        None
+                    },
+                }))
             }
             _ => Err(AppError::Custom(
                 "✘ Cannot decode node from rlp data!".to_string(),
@@ -245,16 +307,45 @@ fn update_child_nodes(
     Ok(child_nodes)
 }
 
-pub fn get_node_from_database(database: &Database, key: &H256) -> Result<Option<Node>> {
-    match get_thing_from_database(database, key) {
+pub fn get_node_from_database<D: NodeStore>(database: &D, key: &H256) -> Result<Option<Node>> {
+    match get_thing_from_database(database, key)? {
         None => Ok(None),
         Some(encoded_node) => Ok(Some(rlp_decode_node(encoded_node)?)),
     }
 }
 
+pub fn get_node_from_reference<D: NodeStore>(
+    database: &D,
+    reference: &[Byte],
+) -> Result<Option<Node>> {
+    match is_inline_node_reference(reference) {
+        true => rlp_decode_node(reference.to_vec()).map(Some),
+        false => get_node_from_database(database, &convert_bytes_to_h256(reference)?),
+    }
+}
+
+/**
+ * As `get_node_from_reference`, but a hashed reference with no node in the
+ * store is an `AppError::MissingNodeError`, as when walking a partial trie
+ * beyond the nodes it holds.
+ *
+ */
+pub fn get_existing_node_from_reference<D: NodeStore>(
+    database: &D,
+    reference: &[Byte],
+) -> Result<Node> {
+    match get_node_from_reference(database, reference)? {
+        Some(node) => Ok(node),
+        None => Err(AppError::MissingNodeError(convert_bytes_to_h256(
+            reference,
+        )?)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::HASHED_NULL_NODE;
     use crate::get_database::{get_new_database, put_thing_in_database};
     use crate::nibble_utils::{get_length_in_nibbles, get_nibbles_from_bytes};
     use crate::test_utils::{
@@ -279,6 +370,13 @@ mod tests {
             .unwrap()
     }
 
+    fn get_branch_from_node(node: Node) -> BranchNode {
+        match node {
+            Node::Branch(branch) => branch,
+            _ => panic!("Node should be a branch node"),
+        }
+    }
+
     fn get_sample_leaf_node_expected_hash() -> H256 {
         let hex = "c9161ce49c6a3362f5d20db4b6e36c259c9624eac5f99e64a052f45035d14c5d".to_string();
         convert_hex_to_h256(hex).unwrap()
@@ -307,14 +405,9 @@ mod tests {
         let result = Node::get_new_leaf_node(path_nibbles.clone(), value.clone()).unwrap();
         let node_type = result.clone().get_type();
         assert!(node_type == LEAF_NODE_STRING);
-        if let Some(_) = result.extension {
-            panic!("{}", panic_str)
-        } else if let Some(_) = result.branch {
-            panic!("{}", panic_str)
-        }
-        match result.leaf {
-            None => panic!("{}", panic_str),
-            Some(leaf) => {
+        match result {
+            Node::Null | Node::Extension(_) | Node::Branch(_) => panic!("{}", panic_str),
+            Node::Leaf(leaf) => {
                 let nibble_length = get_length_in_nibbles(&leaf.path_nibbles.clone());
                 assert!(leaf.value == value);
                 assert!(leaf.raw == expected_raw);
@@ -358,14 +451,9 @@ mod tests {
         assert!(node_type == EXTENSION_NODE_STRING);
         let mut expected_raw = expected_encoded_path.clone();
         expected_raw.append(&mut value.clone());
-        if let Some(_) = result.leaf {
-            panic!("{}", panic_str)
-        } else if let Some(_) = result.branch {
-            panic!("{}", panic_str)
-        }
-        match result.extension {
-            None => panic!("{}", panic_str),
-            Some(extension) => {
+        match result {
+            Node::Null | Node::Leaf(_) | Node::Branch(_) => panic!("{}", panic_str),
+            Node::Extension(extension) => {
                 let nibble_length = get_length_in_nibbles(&extension.path_nibbles.clone());
                 assert!(extension.value == value);
                 assert!(extension.raw == expected_raw);
@@ -396,16 +484,11 @@ mod tests {
     fn should_get_new_branch_with_no_value_correctly() {
         let panic_str = "Node should be a branch node";
         let result = Node::get_new_branch_node(None).unwrap();
-        if let Some(_) = result.extension {
-            panic!("{}", panic_str)
-        } else if let Some(_) = result.leaf {
-            panic!("{}", panic_str)
-        }
         let node_type = result.clone().get_type();
         assert!(node_type == BRANCH_NODE_STRING);
-        match result.branch {
-            None => panic!("{}", panic_str),
-            Some(branch) => {
+        match result {
+            Node::Null | Node::Leaf(_) | Node::Extension(_) => panic!("{}", panic_str),
+            Node::Branch(branch) => {
                 if let Some(_) = branch.value {
                     panic!("Branch should not have a value!")
                 };
@@ -419,16 +502,11 @@ mod tests {
         let panic_str = "Node should be a branch node";
         let value = hex::decode("c0ffee").unwrap();
         let result = Node::get_new_branch_node(Some(value.clone())).unwrap();
-        if let Some(_) = result.extension {
-            panic!("{}", panic_str)
-        } else if let Some(_) = result.leaf {
-            panic!("{}", panic_str)
-        }
         let node_type = result.clone().get_type();
         assert!(node_type == "branch".to_string());
-        match result.branch {
-            None => panic!("{}", panic_str),
-            Some(branch) => {
+        match result {
+            Node::Null | Node::Leaf(_) | Node::Extension(_) => panic!("{}", panic_str),
+            Node::Branch(branch) => {
                 match branch.value {
                     Some(_value) => assert!(_value == value),
                     None => panic!("Branch should have a value!"),
@@ -444,11 +522,11 @@ mod tests {
         let value = None;
         let branch_value = hex::decode("c0ffee").unwrap();
         let branch_node = Node::get_new_branch_node(value).unwrap();
-        assert!(branch_node.clone().branch.unwrap().branches[index] == None);
+        assert!(get_branch_from_node(branch_node.clone()).branches[index].is_none());
         let result = branch_node
             .update_branch_at_index(Some(branch_value.clone()), index)
             .unwrap();
-        assert!(result.clone().branch.unwrap().branches[index] == Some(branch_value));
+        assert!(get_branch_from_node(result).branches[index] == Some(branch_value));
     }
 
     #[test]
@@ -461,6 +539,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_update_branch_value_correctly() {
+        let index = 5;
+        let child = hex::decode("c0ffee").unwrap();
+        let branch_value = hex::decode("decaff").unwrap();
+        let branch_node = Node::get_new_branch_node(None)
+            .and_then(|node| node.update_branch_at_index(Some(child.clone()), index))
+            .unwrap();
+        let result = branch_node
+            .update_branch_value(Some(branch_value.clone()))
+            .unwrap();
+        assert!(result.get_value() == Some(branch_value));
+        assert!(get_branch_from_node(result).branches[index] == Some(child));
+    }
+
+    #[test]
+    fn should_fail_to_update_value_of_non_branch_node() {
+        let expected_error = "✘ Cannot update value - not a branch node!";
+        let non_branch_node = get_sample_leaf_node();
+        match non_branch_node.update_branch_value(None) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Did not receive expected error!"),
+        }
+    }
+
     #[test]
     fn should_rlp_encode_branch_node_correctly() {
         let branch_node = get_sample_branch_node();
@@ -555,34 +658,34 @@ mod tests {
 
     #[test]
     fn should_get_leaf_node_from_database() {
-        let database = get_new_database().unwrap();
+        let mut database = get_new_database().unwrap();
         let node = get_sample_leaf_node();
         let key = node.get_hash().unwrap();
         let rlp_encoded_node = node.get_rlp_encoding().unwrap();
-        let updated_database = put_thing_in_database(database, key, rlp_encoded_node).unwrap();
-        let result = get_node_from_database(&updated_database, &key).unwrap();
+        put_thing_in_database(&mut database, key, rlp_encoded_node).unwrap();
+        let result = get_node_from_database(&database, &key).unwrap();
         assert!(result == Some(node));
     }
 
     #[test]
     fn should_get_branch_node_from_database() {
-        let database = get_new_database().unwrap();
+        let mut database = get_new_database().unwrap();
         let node = get_sample_branch_node();
         let key = node.get_hash().unwrap();
         let rlp_encoded_node = node.get_rlp_encoding().unwrap();
-        let updated_database = put_thing_in_database(database, key, rlp_encoded_node).unwrap();
-        let result = get_node_from_database(&updated_database, &key).unwrap();
+        put_thing_in_database(&mut database, key, rlp_encoded_node).unwrap();
+        let result = get_node_from_database(&database, &key).unwrap();
         assert!(result == Some(node));
     }
 
     #[test]
     fn should_get_extension_node_from_database() {
-        let database = get_new_database().unwrap();
+        let mut database = get_new_database().unwrap();
         let node = get_sample_branch_node();
         let key = node.get_hash().unwrap();
         let rlp_encoded_node = node.get_rlp_encoding().unwrap();
-        let updated_database = put_thing_in_database(database, key, rlp_encoded_node).unwrap();
-        let result = get_node_from_database(&updated_database, &key).unwrap();
+        put_thing_in_database(&mut database, key, rlp_encoded_node).unwrap();
+        let result = get_node_from_database(&database, &key).unwrap();
         assert!(result == Some(node));
     }
 
@@ -618,4 +721,120 @@ mod tests {
         let result = node.get_key_length();
         assert!(result == expected_result);
     }
+
+    #[test]
+    fn should_get_short_node_reference_as_its_rlp_encoding() {
+        let node = get_sample_leaf_node();
+        let expected_result = get_sample_leaf_node_expected_encoding();
+        let result = node.get_reference().unwrap();
+        assert!(result == expected_result);
+        assert!(node.is_inline().unwrap());
+        assert!(is_inline_node_reference(&result));
+    }
+
+    #[test]
+    fn should_get_long_node_reference_as_its_hash() {
+        let node = get_sample_extension_node();
+        let expected_result = get_sample_extension_node_expected_hash()
+            .as_bytes()
+            .to_vec();
+        let result = node.get_reference().unwrap();
+        assert!(result == expected_result);
+        assert!(!node.is_inline().unwrap());
+        assert!(!is_inline_node_reference(&result));
+    }
+
+    #[test]
+    fn should_embed_inline_child_in_branch_encoding_and_decode_it_back() {
+        let child_reference = get_sample_leaf_node().get_reference().unwrap();
+        let node = Node::get_new_branch_node(None)
+            .and_then(|node| node.update_branch_at_index(Some(child_reference), 3))
+            .unwrap();
+        let expected_encoding =
+            hex::decode("da808080c9842012345683c0ffee80808080808080808080808080").unwrap();
+        let encoding = node.get_rlp_encoding().unwrap();
+        assert!(encoding == expected_encoding);
+        let result = rlp_decode_node(encoding).unwrap();
+        assert!(result == node);
+    }
+
+    #[test]
+    fn should_get_inline_node_from_reference_without_database() {
+        let node = get_sample_leaf_node();
+        let database = get_new_database().unwrap();
+        let result = get_node_from_reference(&database, &node.get_reference().unwrap()).unwrap();
+        assert!(result == Some(node));
+    }
+
+    #[test]
+    fn should_get_hashed_node_from_reference_via_database() {
+        let node = get_sample_extension_node();
+        let reference = node.get_reference().unwrap();
+        let mut database = get_new_database().unwrap();
+        assert!(get_node_from_reference(&database, &reference)
+            .unwrap()
+            .is_none());
+        put_thing_in_database(
+            &mut database,
+            node.get_hash().unwrap(),
+            node.get_rlp_encoding().unwrap(),
+        )
+        .unwrap();
+        let result = get_node_from_reference(&database, &reference).unwrap();
+        assert!(result == Some(node));
+    }
+
+    #[test]
+    fn should_get_missing_node_error_for_hashed_reference_not_in_database() {
+        let node = get_sample_extension_node();
+        let database = get_new_database().unwrap();
+        match get_existing_node_from_reference(&database, &node.get_reference().unwrap()) {
+            Err(AppError::MissingNodeError(hash)) => assert!(hash == node.get_hash().unwrap()),
+            _ => panic!("Should get missing node error for node not in database!"),
+        }
+    }
+
+    #[test]
+    fn should_get_child_references_of_nodes() {
+        let extension_node = get_sample_extension_node();
+        let branch_node = get_sample_branch_node();
+        let expected_branch_references = match &branch_node {
+            Node::Branch(branch) => branch
+                .branches
+                .iter()
+                .flatten()
+                .cloned()
+                .collect::<Vec<Bytes>>(),
+            _ => panic!("Sample branch node should be a branch node!"),
+        };
+        assert!(get_sample_leaf_node().get_child_references().is_empty());
+        assert!(Node::Null.get_child_references().is_empty());
+        assert!(extension_node.get_child_references() == vec![extension_node.get_value().unwrap()]);
+        assert!(!expected_branch_references.is_empty());
+        assert!(branch_node.get_child_references() == expected_branch_references);
+    }
+
+    #[test]
+    fn should_rlp_encode_null_node_to_hash_of_empty_trie() {
+        let node = Node::Null;
+        let expected_encoding = vec![0x80];
+        assert!(node.get_type() == NULL_NODE_STRING);
+        assert!(node.get_rlp_encoding().unwrap() == expected_encoding);
+        assert!(node.get_hash().unwrap() == HASHED_NULL_NODE);
+    }
+
+    #[test]
+    fn should_rlp_decode_null_node() {
+        let rlp_encoded_node = Node::Null.get_rlp_encoding().unwrap();
+        let result = rlp_decode_node(rlp_encoded_node).unwrap();
+        assert!(result == Node::Null);
+    }
+
+    #[test]
+    fn should_get_no_key_or_value_from_null_node() {
+        let node = Node::Null;
+        assert!(node.get_key() == EMPTY_NIBBLES);
+        assert!(node.get_key_length() == 0);
+        assert!(node.get_value().is_none());
+    }
 }
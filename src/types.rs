@@ -1,3 +1,4 @@
+use crate::constants::{ACCESS_LIST_TX_TYPE, BLOB_TX_TYPE, LEGACY_TX_TYPE, SET_CODE_TX_TYPE};
 use crate::errors::AppError;
 use crate::trie_nodes::Node;
 use ethereum_types::{Address, Bloom, H256, U256};
@@ -25,6 +26,16 @@ pub struct ReceiptRpcResponse {
     pub result: ReceiptJson,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct TransactionRpcResponse {
+    pub result: TransactionJson,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProofRpcResponse {
+    pub result: ProofJson,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct Block {
     pub author: Address,
@@ -40,22 +51,74 @@ pub struct Block {
     pub number: U256,
     pub parent_hash: H256,
     pub receipts_root: H256,
-    pub seal_fields: (Bytes, U256),
+    pub seal_fields: Option<(Bytes, U256)>,
     pub sha3_uncles: H256,
     pub size: U256,
     pub state_root: H256,
     pub timestamp: U256,
-    pub total_difficulty: U256,
+    pub total_difficulty: Option<U256>,
     pub transactions: Vec<H256>,
     pub transactions_root: H256,
     pub uncles: Vec<H256>,
+    // NOTE: Optional header fields, in the order each fork appended them to the header.
+    pub base_fee_per_gas: Option<U256>,
+    pub withdrawals_root: Option<H256>,
+    pub blob_gas_used: Option<U256>,
+    pub excess_blob_gas: Option<U256>,
+    pub parent_beacon_block_root: Option<H256>,
+    pub requests_hash: Option<H256>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+pub struct BlockHeader<'a>(pub &'a Block);
+
+impl<'a> Encodable for BlockHeader<'a> {
+    fn rlp_append(&self, rlp_stream: &mut RlpStream) {
+        let block = self.0;
+        let mut nonce = [0u8; 32];
+        block.nonce.to_big_endian(&mut nonce);
+        let rlp = rlp_stream.begin_unbounded_list();
+        rlp.append(&block.parent_hash)
+            .append(&block.sha3_uncles)
+            .append(&block.miner)
+            .append(&block.state_root)
+            .append(&block.transactions_root)
+            .append(&block.receipts_root)
+            .append(&block.logs_bloom)
+            .append(&block.difficulty)
+            .append(&block.number)
+            .append(&block.gas_limit)
+            .append(&block.gas_used)
+            .append(&block.timestamp)
+            .append(&block.extra_data)
+            .append(&block.mix_hash)
+            .append(&nonce[24..].to_vec()); // NOTE: The nonce is always encoded as 8 bytes.
+        if let Some(base_fee_per_gas) = &block.base_fee_per_gas {
+            rlp.append(base_fee_per_gas);
+        };
+        if let Some(withdrawals_root) = &block.withdrawals_root {
+            rlp.append(withdrawals_root);
+        };
+        if let Some(blob_gas_used) = &block.blob_gas_used {
+            rlp.append(blob_gas_used);
+        };
+        if let Some(excess_blob_gas) = &block.excess_blob_gas {
+            rlp.append(excess_blob_gas);
+        };
+        if let Some(parent_beacon_block_root) = &block.parent_beacon_block_root {
+            rlp.append(parent_beacon_block_root);
+        };
+        if let Some(requests_hash) = &block.requests_hash {
+            rlp.append(requests_hash);
+        };
+        rlp.complete_unbounded_list();
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct Receipt {
     pub to: Address,
     pub from: Address,
-    pub status: bool,
+    pub outcome: ReceiptOutcome,
     pub gas_used: U256,
     pub block_hash: H256,
     pub transaction_hash: H256,
@@ -64,16 +127,23 @@ pub struct Receipt {
     pub transaction_index: U256,
     pub contract_address: Address,
     pub logs: Vec<Log>,
-    //pub root: H256,
     pub logs_bloom: Bloom,
+    pub tx_type: Byte,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReceiptOutcome {
+    Status(bool),
+    PostStateRoot(H256), // NOTE: Pre-Byzantium receipts commit to a state root instead.
 }
 
 impl Encodable for Receipt {
     fn rlp_append(&self, rlp_stream: &mut RlpStream) {
         let rlp = rlp_stream.begin_list(4);
-        match &self.status {
-            true => rlp.append(&self.status),
-            false => rlp.append_empty_data(),
+        match &self.outcome {
+            ReceiptOutcome::Status(true) => rlp.append(&true),
+            ReceiptOutcome::Status(false) => rlp.append_empty_data(),
+            ReceiptOutcome::PostStateRoot(root) => rlp.append(root),
         };
         rlp.append(&self.cumulative_gas_used)
             .append(&self.logs_bloom)
@@ -98,10 +168,129 @@ impl Encodable for Log {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct Transaction {
+    pub tx_type: Byte,
+    pub hash: H256,
+    pub transaction_index: U256,
+    pub chain_id: Option<U256>,
+    pub nonce: U256,
+    pub gas_price: Option<U256>,
+    pub max_priority_fee_per_gas: Option<U256>,
+    pub max_fee_per_gas: Option<U256>,
+    pub gas: U256,
+    pub to: Option<Address>,
+    pub value: U256,
+    pub input: Bytes,
+    pub access_list: Vec<AccessListItem>,
+    pub max_fee_per_blob_gas: Option<U256>,
+    pub blob_versioned_hashes: Vec<H256>,
+    pub authorization_list: Vec<Authorization>,
+    pub v: U256,
+    pub r: U256,
+    pub s: U256,
+}
+
+impl Encodable for Transaction {
+    fn rlp_append(&self, rlp_stream: &mut RlpStream) {
+        // NOTE: Fields required by the tx's type are checked for when deserializing it.
+        let rlp = rlp_stream.begin_unbounded_list();
+        if self.tx_type != LEGACY_TX_TYPE {
+            rlp.append(&self.chain_id.unwrap_or_default());
+        };
+        rlp.append(&self.nonce);
+        match self.tx_type {
+            LEGACY_TX_TYPE | ACCESS_LIST_TX_TYPE => rlp.append(&self.gas_price.unwrap_or_default()),
+            _ => rlp
+                .append(&self.max_priority_fee_per_gas.unwrap_or_default())
+                .append(&self.max_fee_per_gas.unwrap_or_default()),
+        };
+        rlp.append(&self.gas);
+        match &self.to {
+            Some(to) => rlp.append(to),
+            None => rlp.append_empty_data(),
+        };
+        rlp.append(&self.value).append(&self.input);
+        if self.tx_type != LEGACY_TX_TYPE {
+            rlp.append_list(&self.access_list);
+        };
+        if self.tx_type == BLOB_TX_TYPE {
+            rlp.append(&self.max_fee_per_blob_gas.unwrap_or_default())
+                .append_list(&self.blob_versioned_hashes);
+        };
+        if self.tx_type == SET_CODE_TX_TYPE {
+            rlp.append_list(&self.authorization_list);
+        };
+        rlp.append(&self.v).append(&self.r).append(&self.s);
+        rlp.complete_unbounded_list();
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AccessListItem {
+    pub address: Address,
+    pub storage_keys: Vec<H256>,
+}
+
+impl Encodable for AccessListItem {
+    fn rlp_append(&self, rlp_stream: &mut RlpStream) {
+        rlp_stream
+            .begin_list(2)
+            .append(&self.address)
+            .append_list(&self.storage_keys);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Authorization {
+    pub chain_id: U256,
+    pub address: Address,
+    pub nonce: U256,
+    pub y_parity: U256,
+    pub r: U256,
+    pub s: U256,
+}
+
+impl Encodable for Authorization {
+    fn rlp_append(&self, rlp_stream: &mut RlpStream) {
+        rlp_stream
+            .begin_list(6)
+            .append(&self.chain_id)
+            .append(&self.address)
+            .append(&self.nonce)
+            .append(&self.y_parity)
+            .append(&self.r)
+            .append(&self.s);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Account {
+    pub nonce: U256,
+    pub balance: U256,
+    pub storage_root: H256,
+    pub code_hash: H256,
+}
+
+#[derive(Clone, Debug)]
+pub struct AccountProof {
+    pub address: Address,
+    pub account: Account,
+    pub account_proof: Vec<Bytes>,
+    pub storage_proof: Vec<StorageProof>,
+}
+
+#[derive(Clone, Debug)]
+pub struct StorageProof {
+    pub key: H256,
+    pub value: U256,
+    pub proof: Vec<Bytes>,
+}
+
 #[allow(non_snake_case)]
 #[derive(Debug, Deserialize)]
 pub struct BlockJson {
-    pub author: String,
+    pub author: Option<String>,
     pub difficulty: String,
     pub extraData: String,
     pub gasLimit: String,
@@ -114,22 +303,29 @@ pub struct BlockJson {
     pub number: String,
     pub parentHash: String,
     pub receiptsRoot: String,
-    pub sealFields: (String, String),
+    pub sealFields: Option<(String, String)>,
     pub sha3Uncles: String,
     pub size: String,
     pub stateRoot: String,
     pub timestamp: String,
-    pub totalDifficulty: String,
+    pub totalDifficulty: Option<String>,
     pub transactions: Vec<String>,
     pub transactionsRoot: String,
     pub uncles: Vec<String>,
+    pub baseFeePerGas: Option<String>,
+    pub withdrawalsRoot: Option<String>,
+    pub blobGasUsed: Option<String>,
+    pub excessBlobGas: Option<String>,
+    pub parentBeaconBlockRoot: Option<String>,
+    pub requestsHash: Option<String>,
 }
 
 #[allow(non_snake_case)]
 #[derive(Debug, Deserialize)]
 pub struct ReceiptJson {
     pub from: String,
-    pub status: String,
+    pub root: Option<String>,
+    pub status: Option<String>,
     pub gasUsed: String,
     pub blockHash: String,
     pub logsBloom: String,
@@ -140,6 +336,7 @@ pub struct ReceiptJson {
     pub transactionIndex: String,
     pub cumulativeGasUsed: String,
     pub contractAddress: serde_json::Value,
+    pub r#type: Option<String>,
 }
 
 #[allow(non_snake_case)]
@@ -147,7 +344,7 @@ pub struct ReceiptJson {
 pub struct LogJson {
     pub data: String,
     pub removed: bool,
-    pub r#type: String,
+    pub r#type: Option<String>,
     pub address: String,
     pub logIndex: String,
     pub blockHash: String,
@@ -156,3 +353,65 @@ pub struct LogJson {
     pub transactionHash: String,
     pub transactionIndex: String,
 }
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+pub struct TransactionJson {
+    pub r#type: Option<String>,
+    pub hash: String,
+    pub transactionIndex: String,
+    pub chainId: Option<String>,
+    pub nonce: String,
+    pub gasPrice: Option<String>,
+    pub maxPriorityFeePerGas: Option<String>,
+    pub maxFeePerGas: Option<String>,
+    pub gas: String,
+    pub to: Option<String>,
+    pub value: String,
+    pub input: String,
+    pub accessList: Option<Vec<AccessListItemJson>>,
+    pub maxFeePerBlobGas: Option<String>,
+    pub blobVersionedHashes: Option<Vec<String>>,
+    pub authorizationList: Option<Vec<AuthorizationJson>>,
+    pub v: String,
+    pub yParity: Option<String>,
+    pub r: String,
+    pub s: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessListItemJson {
+    pub address: String,
+    pub storageKeys: Vec<String>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Deserialize)]
+pub struct AuthorizationJson {
+    pub chainId: String,
+    pub address: String,
+    pub nonce: String,
+    pub yParity: String,
+    pub r: String,
+    pub s: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+pub struct ProofJson {
+    pub address: String,
+    pub accountProof: Vec<String>,
+    pub balance: String,
+    pub codeHash: String,
+    pub nonce: String,
+    pub storageHash: String,
+    pub storageProof: Vec<StorageProofJson>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StorageProofJson {
+    pub key: String,
+    pub value: String,
+    pub proof: Vec<String>,
+}
@@ -8,10 +8,22 @@ pub static USAGE_INFO: &str = "
 
 This tool generates a merkle receipt proof of the receipt pertaining to the given transaction hash.
 
+Using the tx command, it instead generates a merkle proof of the transaction itself, against the block's transactions root.
+
+Using the bundle command, it outputs JSON with the block's RLP-encoded header, the transaction proof, the receipt proof & the trie key they share.
+
+Using the account command, it fetches an EIP-1186 proof of an account & any given storage slots, verifies it against the state root of the block, then prints the proven values.
+
+It can also verify such a proof against a receipts root, printing the rlp-encoded receipt it proves.
+
 ***
 
 Usage:  rusty-receipt-proof-maker [--help]
-        rusty-receipt-proof-maker <txhash> [--verbose | -v]
+        rusty-receipt-proof-maker verify <proof> <receiptsroot> <index> [--verbose | -v]
+        rusty-receipt-proof-maker tx <txhash> [--header] [--verbose | -v]
+        rusty-receipt-proof-maker bundle <txhash> [--verbose | -v]
+        rusty-receipt-proof-maker account <address> [<storagekey>...] [--block=<blocknumber>] [--verbose | -v]
+        rusty-receipt-proof-maker <txhash> [--header] [--verbose | -v]
 
 Options:
 
@@ -19,7 +31,27 @@ Options:
 
     -v, --verbose       ❍ Enable verbose mode for additional output.
 
+    --header            ❍ Output JSON with the proof & the block's RLP-encoded header.
+
     <txhash>            ❍ A transaction hash of an Ethereum transaction
                         ➔ Format: A 32-byte long, prefixed hex string.
 
+    --block=<blocknumber>
+                        ❍ The block whose state root the account is proven against.
+                        ➔ Format: A decimal block number or `latest`.
+                        [default: latest]
+
+    <address>           ❍ The address of the account to prove.
+                        ➔ Format: A 20-byte long, prefixed hex string.
+
+    <storagekey>        ❍ A storage slot of the account to prove.
+                        ➔ Format: A prefixed hex string of at most 32 bytes.
+
+    <proof>             ❍ A hex proof as output by this tool.
+
+    <receiptsroot>      ❍ The receipts root of the block the proof is for.
+                        ➔ Format: A 32-byte long, prefixed hex string.
+
+    <index>             ❍ The index of the proven receipt's transaction in the block.
+
 ";
@@ -1,8 +1,10 @@
 use crate::parse_cli_args::CliArgs;
-use crate::types::Result;
-use crate::validate_tx_hash::validate_tx_hash;
+use rusty_receipt_proof_maker::{validate_tx_hash, Result};
 
 pub fn validate_cli_args(cli_args: CliArgs) -> Result<CliArgs> {
     info!("✔ Validating CLI args...");
-    validate_tx_hash(cli_args.arg_txhash.clone()).map(|_| cli_args)
+    match cli_args.cmd_verify || cli_args.cmd_account {
+        true => Ok(cli_args),
+        false => validate_tx_hash(cli_args.arg_txhash.clone()).map(|_| cli_args),
+    }
 }
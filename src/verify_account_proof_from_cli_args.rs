@@ -0,0 +1,72 @@
+use crate::parse_cli_args::CliArgs;
+use rusty_receipt_proof_maker::{
+    get_account_proof, get_block_by_number, get_endpoint_from_env_vars,
+    verify_account_and_storage_proofs, Account, AccountProof, Result,
+};
+use serde_json::json;
+
+fn get_json_from_verified_account_proof(account_proof: &AccountProof, account: &Account) -> String {
+    json!({
+        "address": format!("{:?}", account_proof.address),
+        "nonce": format!("0x{:x}", account.nonce),
+        "balance": format!("0x{:x}", account.balance),
+        "storageHash": format!("{:?}", account.storage_root),
+        "codeHash": format!("{:?}", account.code_hash),
+        "storage": account_proof
+            .storage_proof
+            .iter()
+            .map(|storage_proof| json!({
+                "key": format!("{:?}", storage_proof.key),
+                "value": format!("0x{:x}", storage_proof.value),
+            }))
+            .collect::<Vec<serde_json::Value>>(),
+    })
+    .to_string()
+}
+
+pub fn verify_account_proof_from_cli_args(cli_args: CliArgs) -> Result<String> {
+    let endpoint = get_endpoint_from_env_vars()?;
+    let block = get_block_by_number(&endpoint, &cli_args.flag_block)?;
+    info!(
+        "✔ Proving account against state root of block: {}",
+        block.number
+    );
+    get_account_proof(
+        &endpoint,
+        &cli_args.arg_address,
+        &cli_args.arg_storagekey,
+        &format!("0x{:x}", block.number),
+    )
+    .and_then(|account_proof| {
+        verify_account_and_storage_proofs(&account_proof, &block.state_root)
+            .map(|account| get_json_from_verified_account_proof(&account_proof, &account))
+    })
+    .inspect(|_| info!("✔ Proofs verified! Account & storage:\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{SAMPLE_ACCOUNT_PROOF_JSON_PATH, STATE_ROOT};
+    use rusty_receipt_proof_maker::{
+        convert_hex_to_h256, get_account_proof_from_rpc_response, verify_account_proof,
+    };
+    use std::fs;
+
+    #[test]
+    fn should_get_json_from_verified_account_proof() {
+        let account_proof = get_account_proof_from_rpc_response(
+            fs::read_to_string(SAMPLE_ACCOUNT_PROOF_JSON_PATH).unwrap(),
+        )
+        .unwrap();
+        let state_root = convert_hex_to_h256(STATE_ROOT.to_string()).unwrap();
+        let account = verify_account_proof(&account_proof, &state_root).unwrap();
+        let result = get_json_from_verified_account_proof(&account_proof, &account);
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert!(json["address"] == "0x7f0d15c7faae65896648c8273b6d7e43f58fa842");
+        assert!(json["nonce"] == "0x1");
+        assert!(json["balance"] == "0xde0b6b3a7640000");
+        assert!(json["storage"].as_array().unwrap().len() == 4);
+        assert!(json["storage"][1]["value"] == "0xc0ffee");
+    }
+}
@@ -0,0 +1,1149 @@
+use crate::{
+    constants::HASHED_NULL_NODE,
+    errors::AppError,
+    get_branch_from_trie::{
+        convert_usize_index_to_trie_key, get_root_from_key_value_tuples,
+        split_key_value_tuples_at_node, KeyValueTuples,
+    },
+    get_keccak_hash::keccak_hash_bytes,
+    nibble_utils::{
+        convert_nibble_to_usize, get_common_prefix_nibbles, split_at_first_nibble, Nibbles,
+    },
+    trie_nodes::{
+        is_inline_node_reference, rlp_decode_node, BranchNode, ExtensionNode, LeafNode, Node,
+    },
+    types::{Byte, Bytes, Database, Result},
+    utils::{convert_bytes_to_h256, decode_prefixed_hex},
+};
+use ethereum_types::H256;
+use rlp::Rlp;
+
+static MALFORMED_PROOF_ERR: &str = "✘ Proof Error: Proof is not a well-formed rlp list of nodes!";
+
+pub fn decode_hex_proof_to_rlp_encoded_nodes(hex_proof: &str) -> Result<Vec<Bytes>> {
+    let proof_bytes = decode_prefixed_hex(hex_proof.to_string())?;
+    let rlp = Rlp::new(&proof_bytes);
+    let payload_info = rlp
+        .payload_info()
+        .map_err(|_| AppError::Custom(MALFORMED_PROOF_ERR.to_string()))?;
+    let rlp_encoded_nodes: Vec<Bytes> = rlp.iter().map(|node| node.as_raw().to_vec()).collect();
+    let decoded_length: usize = rlp_encoded_nodes.iter().map(|node| node.len()).sum();
+    match rlp.is_list()
+        && payload_info.header_len + payload_info.value_len == proof_bytes.len()
+        && decoded_length == payload_info.value_len
+    {
+        true => Ok(rlp_encoded_nodes),
+        false => Err(AppError::Custom(MALFORMED_PROOF_ERR.to_string())),
+    }
+}
+
+fn check_node_hash(rlp_encoded_node: &[u8], expected_hash: &H256) -> Result<()> {
+    let node_hash = keccak_hash_bytes(rlp_encoded_node)?;
+    match &node_hash == expected_hash {
+        true => Ok(()),
+        false => Err(AppError::Custom(format!(
+            "✘ Proof Error: Node hash {:?} does not match its parent's reference {:?}!",
+            node_hash, expected_hash
+        ))),
+    }
+}
+
+/**
+ *
+ * Getting the Next Node from a Proof:
+ *
+ * A child referred to by hash is the next node in the proof, and must hash
+ * to that reference. A child embedded inline in its parent is instead decoded
+ * from the reference itself, leaving the proof's remaining nodes untouched.
+ *
+ */
+fn get_next_node_from_proof<'a>(
+    rlp_encoded_nodes: &'a [Bytes],
+    expected_reference: &[Byte],
+    ran_out_of_nodes_err: &str,
+) -> Result<(Node, &'a [Bytes])> {
+    match is_inline_node_reference(expected_reference) {
+        true => rlp_decode_node(expected_reference.to_vec()).map(|node| (node, rlp_encoded_nodes)),
+        false => match rlp_encoded_nodes.split_first() {
+            None => Err(AppError::Custom(ran_out_of_nodes_err.to_string())),
+            Some((rlp_encoded_node, remaining_nodes)) => {
+                check_node_hash(
+                    rlp_encoded_node,
+                    &convert_bytes_to_h256(expected_reference)?,
+                )?;
+                rlp_decode_node(rlp_encoded_node.clone()).map(|node| (node, remaining_nodes))
+            }
+        },
+    }
+}
+
+pub fn verify_proof_nodes(rlp_encoded_nodes: &[Bytes], root: &H256, key: Nibbles) -> Result<Bytes> {
+    verify_proof_nodes_recursively(rlp_encoded_nodes, root.as_bytes(), key)
+}
+
+fn verify_proof_nodes_recursively(
+    rlp_encoded_nodes: &[Bytes],
+    expected_reference: &[Byte],
+    remaining_key: Nibbles,
+) -> Result<Bytes> {
+    let (node, remaining_nodes) = get_next_node_from_proof(
+        rlp_encoded_nodes,
+        expected_reference,
+        "✘ Proof Error: Ran out of nodes before reaching the key!",
+    )?;
+    match node {
+        Node::Null => Err(AppError::Custom(
+            "✘ Proof Error: Reached the null node of an empty trie!".to_string(),
+        )),
+        Node::Leaf(leaf) => verify_from_leaf_node(leaf, remaining_nodes, remaining_key),
+        Node::Branch(branch) => verify_from_branch_node(branch, remaining_nodes, remaining_key),
+        Node::Extension(extension) => {
+            verify_from_extension_node(extension, remaining_nodes, remaining_key)
+        }
+    }
+}
+/**
+ *
+ * Verifying from a Leaf Node:
+ *
+ * A leaf ends the path, so it must be the last node in the proof, and its
+ * path must consume exactly what remains of the key. Its value is then the
+ * item the proof commits to.
+ *
+ */
+fn verify_from_leaf_node(
+    leaf: LeafNode,
+    remaining_nodes: &[Bytes],
+    remaining_key: Nibbles,
+) -> Result<Bytes> {
+    match leaf.path_nibbles == remaining_key {
+        false => Err(AppError::Custom(
+            "✘ Proof Error: Leaf node's path does not match the key!".to_string(),
+        )),
+        true => match remaining_nodes.is_empty() {
+            false => Err(AppError::Custom(
+                "✘ Proof Error: Proof contains nodes after the leaf node!".to_string(),
+            )),
+            true => Ok(leaf.value),
+        },
+    }
+}
+/**
+ *
+ * Verifying from an Extension Node:
+ *
+ * The extension's path must be entirely consumed by the remaining key, else
+ * the key diverges from the trie here and cannot be proven. The extension's
+ * value refers to the next node, by hash or inline.
+ *
+ */
+fn verify_from_extension_node(
+    extension: ExtensionNode,
+    remaining_nodes: &[Bytes],
+    remaining_key: Nibbles,
+) -> Result<Bytes> {
+    get_common_prefix_nibbles(remaining_key, extension.path_nibbles.clone()).and_then(
+        |(_, key_remainder, node_key_remainder)| match node_key_remainder.len() {
+            0 => verify_proof_nodes_recursively(remaining_nodes, &extension.value, key_remainder),
+            _ => Err(AppError::Custom(
+                "✘ Proof Error: Key diverges from extension node's path!".to_string(),
+            )),
+        },
+    )
+}
+/**
+ *
+ * Verifying from a Branch Node:
+ *
+ * If the key is fully consumed, the proof commits to the branch's own value.
+ * Otherwise the first nibble of the remaining key selects the child whose
+ * hash the next node in the proof must match.
+ *
+ */
+fn verify_from_branch_node(
+    branch: BranchNode,
+    remaining_nodes: &[Bytes],
+    remaining_key: Nibbles,
+) -> Result<Bytes> {
+    match remaining_key.len() {
+        0 => match (remaining_nodes.is_empty(), branch.value) {
+            (true, Some(value)) => Ok(value),
+            _ => Err(AppError::Custom(
+                "✘ Proof Error: Key ends at a branch with no value!".to_string(),
+            )),
+        },
+        _ => split_at_first_nibble(&remaining_key).and_then(|(first_nibble, remaining_nibbles)| {
+            match &branch.branches[convert_nibble_to_usize(first_nibble)] {
+                None => Err(AppError::Custom(
+                    "✘ Proof Error: No child in branch at key's next nibble!".to_string(),
+                )),
+                Some(child_reference) => verify_proof_nodes_recursively(
+                    remaining_nodes,
+                    child_reference,
+                    remaining_nibbles,
+                ),
+            }
+        }),
+    }
+}
+
+fn get_next_node_reference_along_key(
+    node: &Node,
+    key: &Nibbles,
+) -> Result<Option<(Bytes, Nibbles)>> {
+    match node {
+        Node::Extension(extension) => {
+            get_common_prefix_nibbles(key.clone(), extension.path_nibbles.clone()).map(
+                |(_, key_remainder, node_key_remainder)| match node_key_remainder.len() {
+                    0 => Some((extension.value.clone(), key_remainder)),
+                    _ => None,
+                },
+            )
+        }
+        Node::Branch(branch) => match key.len() {
+            0 => Ok(None),
+            _ => split_at_first_nibble(key).map(|(first_nibble, remaining_nibbles)| {
+                branch.branches[convert_nibble_to_usize(first_nibble)]
+                    .clone()
+                    .map(|child_reference| (child_reference, remaining_nibbles))
+            }),
+        },
+        Node::Null | Node::Leaf(_) => Ok(None),
+    }
+}
+
+fn does_node_hold_key(node: &Node, key: &Nibbles) -> Result<bool> {
+    match node {
+        Node::Leaf(leaf) => Ok(&leaf.path_nibbles == key),
+        Node::Branch(branch) => Ok(key.len() == 0 && branch.value.is_some()),
+        Node::Null | Node::Extension(_) => Ok(false),
+    }
+}
+
+pub fn verify_exclusion_proof_nodes(
+    rlp_encoded_nodes: &[Bytes],
+    root: &H256,
+    key: Nibbles,
+) -> Result<()> {
+    match rlp_encoded_nodes.is_empty() && root == &HASHED_NULL_NODE {
+        true => Ok(()),
+        false => verify_exclusion_proof_nodes_recursively(rlp_encoded_nodes, root.as_bytes(), key),
+    }
+}
+/**
+ *
+ * Verifying an Exclusion Proof:
+ *
+ * The key is followed down the proof exactly as for inclusion, until it
+ * reaches a node with no onward reference along the key. That node must be the
+ * last in the proof, and must not itself hold the key: a leaf whose path
+ * differs from the remaining key, a branch with no value or no child at the
+ * key's next nibble, or an extension whose path the key diverges from. Any
+ * of these means no node beneath could hold the key.
+ *
+ */
+fn verify_exclusion_proof_nodes_recursively(
+    rlp_encoded_nodes: &[Bytes],
+    expected_reference: &[Byte],
+    remaining_key: Nibbles,
+) -> Result<()> {
+    let (node, remaining_nodes) = get_next_node_from_proof(
+        rlp_encoded_nodes,
+        expected_reference,
+        "✘ Proof Error: Ran out of nodes before reaching the key's divergence point!",
+    )?;
+    match get_next_node_reference_along_key(&node, &remaining_key)? {
+        Some((child_reference, key_remainder)) => verify_exclusion_proof_nodes_recursively(
+            remaining_nodes,
+            &child_reference,
+            key_remainder,
+        ),
+        None => match (
+            does_node_hold_key(&node, &remaining_key)?,
+            remaining_nodes.is_empty(),
+        ) {
+            (true, _) => Err(AppError::Custom(
+                "✘ Proof Error: Key is in the trie so cannot be excluded!".to_string(),
+            )),
+            (false, false) => Err(AppError::Custom(
+                "✘ Proof Error: Proof contains nodes after the key's divergence point!".to_string(),
+            )),
+            (false, true) => Ok(()),
+        },
+    }
+}
+
+/**
+ *
+ * Pulling a Key's Branch from a Multiproof:
+ *
+ * Starting at the root, each node is looked up in the multiproof by hash, and
+ * the key followed to the reference of the next node, until the key runs out
+ * or diverges from the trie. Inline nodes need no looking up, being decoded
+ * from their reference, & are left out of the branch since they travel within
+ * their parents. The nodes so gathered form an ordinary proof of the key, for
+ * verifying as such. Each node used is recorded, so that unneeded nodes in
+ * the multiproof can be caught.
+ *
+ */
+fn get_branch_from_multiproof_nodes(
+    nodes_by_hash: &Database,
+    used_hashes: &mut Vec<H256>,
+    root: &H256,
+    key: Nibbles,
+) -> Result<Vec<Bytes>> {
+    let mut branch = Vec::new();
+    let mut next = Some((root.as_bytes().to_vec(), key));
+    while let Some((reference, remaining_key)) = next {
+        let rlp_encoded_node = match is_inline_node_reference(&reference) {
+            true => reference,
+            false => {
+                let hash = convert_bytes_to_h256(&reference)?;
+                let rlp_encoded_node = nodes_by_hash.get(&hash).ok_or_else(|| {
+                    AppError::Custom(format!(
+                        "✘ Proof Error: Multiproof is missing node {:?}!",
+                        hash
+                    ))
+                })?;
+                if !used_hashes.contains(&hash) {
+                    used_hashes.push(hash)
+                };
+                branch.push(rlp_encoded_node.clone());
+                rlp_encoded_node.clone()
+            }
+        };
+        next =
+            get_next_node_reference_along_key(&rlp_decode_node(rlp_encoded_node)?, &remaining_key)?;
+    }
+    Ok(branch)
+}
+
+pub fn verify_multiproof_nodes(
+    rlp_encoded_nodes: &[Bytes],
+    root: &H256,
+    keys: Vec<Nibbles>,
+) -> Result<Vec<Bytes>> {
+    let nodes_by_hash = rlp_encoded_nodes
+        .iter()
+        .map(|node| Ok((keccak_hash_bytes(node)?, node.clone())))
+        .collect::<Result<Database>>()?;
+    let mut used_hashes = Vec::new();
+    let values = keys
+        .into_iter()
+        .map(|key| {
+            get_branch_from_multiproof_nodes(&nodes_by_hash, &mut used_hashes, root, key.clone())
+                .and_then(|branch| verify_proof_nodes(&branch, root, key))
+        })
+        .collect::<Result<Vec<Bytes>>>()?;
+    match used_hashes.len() == rlp_encoded_nodes.len() {
+        true => Ok(values),
+        false => Err(AppError::Custom(
+            "✘ Proof Error: Multiproof contains duplicate nodes or nodes no key needs!".to_string(),
+        )),
+    }
+}
+
+/**
+ *
+ * Verifying a Range Proof:
+ *
+ * The range's key-values are handed down the trie from the root. Where the
+ * proof holds the node at hand, or it's inline in its parent, its keys and
+ * values are checked against the node & the rest handed on to its children.
+ * Where it does not, the subtree must lie wholly within the range, so it is
+ * rebuilt from the key-values alone and its root checked against the parent's
+ * reference. Any receipt omitted from the range would change that root.
+ *
+ */
+fn verify_range_from_reference(
+    nodes_by_hash: &Database,
+    used_hashes: &mut Vec<H256>,
+    reference: Bytes,
+    key_value_tuples: KeyValueTuples,
+) -> Result<()> {
+    if is_inline_node_reference(&reference) {
+        return split_key_value_tuples_at_node(&rlp_decode_node(reference)?, key_value_tuples)?
+            .into_iter()
+            .try_for_each(|(child_reference, child_key_value_tuples)| {
+                verify_range_from_reference(
+                    nodes_by_hash,
+                    used_hashes,
+                    child_reference,
+                    child_key_value_tuples,
+                )
+            });
+    };
+    let hash = convert_bytes_to_h256(&reference)?;
+    match nodes_by_hash.get(&hash) {
+        None => {
+            let rebuilt_hash = get_root_from_key_value_tuples(key_value_tuples)?;
+            match rebuilt_hash == hash {
+                true => Ok(()),
+                false => Err(AppError::Custom(format!(
+                    "✘ Proof Error: Rebuilt subtree {:?} does not match its parent's reference {:?}!",
+                    rebuilt_hash, hash
+                ))),
+            }
+        }
+        Some(rlp_encoded_node) => {
+            if !used_hashes.contains(&hash) {
+                used_hashes.push(hash)
+            };
+            split_key_value_tuples_at_node(
+                &rlp_decode_node(rlp_encoded_node.clone())?,
+                key_value_tuples,
+            )?
+            .into_iter()
+            .try_for_each(|(child_reference, child_key_value_tuples)| {
+                verify_range_from_reference(
+                    nodes_by_hash,
+                    used_hashes,
+                    child_reference,
+                    child_key_value_tuples,
+                )
+            })
+        }
+    }
+}
+
+pub fn verify_range_proof_nodes(
+    rlp_encoded_nodes: &[Bytes],
+    root: &H256,
+    key_value_tuples: KeyValueTuples,
+) -> Result<()> {
+    let nodes_by_hash = rlp_encoded_nodes
+        .iter()
+        .map(|node| Ok((keccak_hash_bytes(node)?, node.clone())))
+        .collect::<Result<Database>>()?;
+    let mut used_hashes = Vec::new();
+    verify_range_from_reference(
+        &nodes_by_hash,
+        &mut used_hashes,
+        root.as_bytes().to_vec(),
+        key_value_tuples,
+    )?;
+    match used_hashes.len() == rlp_encoded_nodes.len() {
+        true => Ok(()),
+        false => Err(AppError::Custom(
+            "✘ Proof Error: Range proof contains duplicate nodes or nodes the range does not need!"
+                .to_string(),
+        )),
+    }
+}
+
+pub fn verify_receipt_range_proof(
+    hex_proof: &str,
+    receipts_root: &H256,
+    first_index: usize,
+    last_index: usize,
+    rlp_encoded_receipts: &[Bytes],
+) -> Result<()> {
+    info!(
+        "✔ Verifying receipt range proof of indices [{}, {}] against receipts root: {}",
+        first_index, last_index, receipts_root
+    );
+    if first_index > last_index || last_index - first_index + 1 != rlp_encoded_receipts.len() {
+        return Err(AppError::Custom(format!(
+            "✘ Proof Error: Range [{}, {}] does not match the {} receipts given!",
+            first_index,
+            last_index,
+            rlp_encoded_receipts.len()
+        )));
+    };
+    decode_hex_proof_to_rlp_encoded_nodes(hex_proof).and_then(|rlp_encoded_nodes| {
+        verify_range_proof_nodes(
+            &rlp_encoded_nodes,
+            receipts_root,
+            (first_index..=last_index)
+                .zip(rlp_encoded_receipts.iter())
+                .map(|(index, receipt)| {
+                    Ok((convert_usize_index_to_trie_key(index)?, receipt.clone()))
+                })
+                .collect::<Result<KeyValueTuples>>()?,
+        )
+    })
+}
+
+pub fn verify_receipt_multiproof(
+    hex_proof: &str,
+    receipts_root: &H256,
+    indices: &[usize],
+) -> Result<Vec<Bytes>> {
+    info!(
+        "✔ Verifying receipt multiproof of {} receipts against receipts root: {}",
+        indices.len(),
+        receipts_root
+    );
+    decode_hex_proof_to_rlp_encoded_nodes(hex_proof).and_then(|rlp_encoded_nodes| {
+        verify_multiproof_nodes(
+            &rlp_encoded_nodes,
+            receipts_root,
+            indices
+                .iter()
+                .map(|index| convert_usize_index_to_trie_key(*index))
+                .collect::<Result<Vec<Nibbles>>>()?,
+        )
+    })
+}
+
+pub fn verify_receipt_proof(hex_proof: &str, receipts_root: &H256, index: usize) -> Result<Bytes> {
+    info!(
+        "✔ Verifying receipt proof against receipts root: {}",
+        receipts_root
+    );
+    decode_hex_proof_to_rlp_encoded_nodes(hex_proof).and_then(|rlp_encoded_nodes| {
+        verify_proof_nodes(
+            &rlp_encoded_nodes,
+            receipts_root,
+            convert_usize_index_to_trie_key(index)?,
+        )
+    })
+}
+
+pub fn verify_transaction_proof(
+    hex_proof: &str,
+    transactions_root: &H256,
+    index: usize,
+) -> Result<Bytes> {
+    info!(
+        "✔ Verifying transaction proof against transactions root: {}",
+        transactions_root
+    );
+    decode_hex_proof_to_rlp_encoded_nodes(hex_proof).and_then(|rlp_encoded_nodes| {
+        verify_proof_nodes(
+            &rlp_encoded_nodes,
+            transactions_root,
+            convert_usize_index_to_trie_key(index)?,
+        )
+    })
+}
+
+pub fn verify_receipt_exclusion_proof(
+    hex_proof: &str,
+    receipts_root: &H256,
+    index: usize,
+) -> Result<()> {
+    info!(
+        "✔ Verifying receipt exclusion proof against receipts root: {}",
+        receipts_root
+    );
+    decode_hex_proof_to_rlp_encoded_nodes(hex_proof).and_then(|rlp_encoded_nodes| {
+        verify_exclusion_proof_nodes(
+            &rlp_encoded_nodes,
+            receipts_root,
+            convert_usize_index_to_trie_key(index)?,
+        )
+    })
+}
+
+pub fn verify_transaction_exclusion_proof(
+    hex_proof: &str,
+    transactions_root: &H256,
+    index: usize,
+) -> Result<()> {
+    info!(
+        "✔ Verifying transaction exclusion proof against transactions root: {}",
+        transactions_root
+    );
+    decode_hex_proof_to_rlp_encoded_nodes(hex_proof).and_then(|rlp_encoded_nodes| {
+        verify_exclusion_proof_nodes(
+            &rlp_encoded_nodes,
+            transactions_root,
+            convert_usize_index_to_trie_key(index)?,
+        )
+    })
+}
+
+pub fn verify_transaction_count_proof(
+    inclusion_hex_proof: &str,
+    exclusion_hex_proof: &str,
+    transactions_root: &H256,
+    count: usize,
+) -> Result<usize> {
+    info!(
+        "✔ Verifying block contains exactly {} transactions...",
+        count
+    );
+    match count {
+        0 => match decode_hex_proof_to_rlp_encoded_nodes(inclusion_hex_proof)?.is_empty() {
+            true => Ok(()),
+            false => Err(AppError::Custom(
+                "✘ Proof Error: A count of zero needs an empty inclusion proof!".to_string(),
+            )),
+        },
+        _ => {
+            verify_transaction_proof(inclusion_hex_proof, transactions_root, count - 1).map(|_| ())
+        }
+    }
+    .and_then(|_| verify_transaction_exclusion_proof(exclusion_hex_proof, transactions_root, count))
+    .map(|_| count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_branch_from_trie::{
+        get_branches_from_trie, get_exclusion_branch_from_trie, get_range_branch_from_trie,
+        get_transaction_count_branches_from_trie,
+    };
+    use crate::get_hex_proof_from_branch::get_hex_proof_from_branch;
+    use crate::get_transactions_trie::get_transactions_trie_from_transactions;
+    use crate::nibble_utils::get_nibbles_from_bytes;
+    use crate::rlp_codec::get_rlp_encoded_receipts_and_nibble_tuples;
+    use crate::test_utils::{
+        get_rlp_encoded_nodes_from_node_stack, get_sample_inline_key_value_tuples,
+        get_sample_proof_1, get_sample_proof_3, get_sample_receipts, get_sample_transactions,
+        get_sample_trie_with_indexed_values, get_sample_trie_with_inline_nodes,
+        get_sample_trie_with_sample_receipts, get_sample_tx_hashes_1, get_sample_tx_hashes_3,
+        INLINE_TRIE_ROOT, PROOF_1_INDEX, PROOF_3_INDEX, RECEIPTS_ROOT_1, RECEIPTS_ROOT_3,
+        SAMPLE_RECEIPT_JSONS_1_PATH, SAMPLE_RECEIPT_JSONS_3_PATH, TRANSACTIONS_ROOT,
+    };
+    use crate::types::NodeStack;
+    use crate::utils::{convert_bytes_to_hex, convert_hex_to_h256};
+
+    const MULTIPROOF_INDICES: [usize; 4] = [0, 3, 14, 15];
+
+    fn get_sample_multiproof() -> String {
+        get_hex_proof_from_branch(
+            &get_branches_from_trie(
+                &get_sample_trie_with_sample_receipts(
+                    SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+                    get_sample_tx_hashes_1(),
+                ),
+                &MULTIPROOF_INDICES,
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn get_sample_multiproof_nodes() -> Vec<Bytes> {
+        decode_hex_proof_to_rlp_encoded_nodes(&get_sample_multiproof()).unwrap()
+    }
+
+    fn get_sample_multiproof_keys() -> Vec<Nibbles> {
+        MULTIPROOF_INDICES
+            .iter()
+            .map(|index| convert_usize_index_to_trie_key(*index).unwrap())
+            .collect()
+    }
+
+    fn get_expected_rlp_encoded_receipt(path: &str, tx_hashes: Vec<String>, index: usize) -> Bytes {
+        let receipts = get_sample_receipts(path.to_string(), tx_hashes);
+        let receipt = receipts
+            .iter()
+            .find(|receipt| receipt.transaction_index.as_usize() == index)
+            .unwrap();
+        get_rlp_encoded_receipts_and_nibble_tuples(std::slice::from_ref(receipt)).unwrap()[0]
+            .1
+            .clone()
+    }
+
+    #[test]
+    fn should_decode_hex_proof_to_rlp_encoded_nodes() {
+        let hex_proof = get_sample_proof_1();
+        let result = decode_hex_proof_to_rlp_encoded_nodes(&hex_proof).unwrap();
+        let re_encoded = {
+            let mut rlp_stream = rlp::RlpStream::new_list(result.len());
+            result.iter().for_each(|node| {
+                rlp_stream.append_raw(node, 1);
+            });
+            convert_bytes_to_hex(rlp_stream.out())
+        };
+        assert!(re_encoded == hex_proof);
+        result
+            .iter()
+            .for_each(|node| assert!(rlp_decode_node(node.clone()).is_ok()));
+    }
+
+    #[test]
+    fn should_fail_to_decode_malformed_hex_proof() {
+        let expected_error = MALFORMED_PROOF_ERR;
+        let hex_proof = get_sample_proof_1();
+        let truncated_proof = &hex_proof[..hex_proof.len() - 2];
+        match decode_hex_proof_to_rlp_encoded_nodes(truncated_proof) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Decoding a truncated proof should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_verify_receipt_proof_1() {
+        let expected_result = get_expected_rlp_encoded_receipt(
+            SAMPLE_RECEIPT_JSONS_1_PATH,
+            get_sample_tx_hashes_1(),
+            PROOF_1_INDEX,
+        );
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let result = verify_receipt_proof(&get_sample_proof_1(), &root, PROOF_1_INDEX).unwrap();
+        assert!(result == expected_result);
+    }
+
+    #[test]
+    fn should_verify_receipt_proof_3() {
+        let expected_result = get_expected_rlp_encoded_receipt(
+            SAMPLE_RECEIPT_JSONS_3_PATH,
+            get_sample_tx_hashes_3(),
+            PROOF_3_INDEX,
+        );
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_3.to_string()).unwrap();
+        let result = verify_receipt_proof(&get_sample_proof_3(), &root, PROOF_3_INDEX).unwrap();
+        assert!(result == expected_result);
+    }
+
+    #[test]
+    fn should_fail_to_verify_proof_against_wrong_root() {
+        let wrong_root = convert_hex_to_h256(RECEIPTS_ROOT_3.to_string()).unwrap();
+        match verify_receipt_proof(&get_sample_proof_1(), &wrong_root, PROOF_1_INDEX) {
+            Err(AppError::Custom(e)) => assert!(e.contains("does not match its parent's")),
+            _ => panic!("Verifying against the wrong root should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_proof_for_wrong_index() {
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        assert!(verify_receipt_proof(&get_sample_proof_1(), &root, PROOF_1_INDEX + 1).is_err());
+    }
+
+    #[test]
+    fn should_fail_to_verify_tampered_proof() {
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let hex_proof = get_sample_proof_1();
+        let tampered_proof = format!(
+            "{}{}",
+            &hex_proof[..hex_proof.len() - 2],
+            match &hex_proof[hex_proof.len() - 2..] {
+                "00" => "01",
+                _ => "00",
+            }
+        );
+        match verify_receipt_proof(&tampered_proof, &root, PROOF_1_INDEX) {
+            Err(AppError::Custom(e)) => assert!(e.contains("does not match its parent's")),
+            _ => panic!("Verifying a tampered proof should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_truncated_node_stack() {
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let mut rlp_encoded_nodes =
+            decode_hex_proof_to_rlp_encoded_nodes(&get_sample_proof_1()).unwrap();
+        rlp_encoded_nodes.pop();
+        let key = convert_usize_index_to_trie_key(PROOF_1_INDEX).unwrap();
+        match verify_proof_nodes(&rlp_encoded_nodes, &root, key) {
+            Err(AppError::Custom(e)) => {
+                assert!(e == "✘ Proof Error: Ran out of nodes before reaching the key!")
+            }
+            _ => panic!("Verifying a truncated proof should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_verify_receipt_multiproof() {
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let hex_proof = get_sample_multiproof();
+        let result = verify_receipt_multiproof(&hex_proof, &root, &MULTIPROOF_INDICES).unwrap();
+        MULTIPROOF_INDICES
+            .iter()
+            .zip(result.iter())
+            .for_each(|(index, rlp_encoded_receipt)| {
+                assert!(
+                    rlp_encoded_receipt
+                        == &get_expected_rlp_encoded_receipt(
+                            SAMPLE_RECEIPT_JSONS_1_PATH,
+                            get_sample_tx_hashes_1(),
+                            *index,
+                        )
+                )
+            });
+    }
+
+    #[test]
+    fn should_verify_multiproof_nodes_for_subset_of_keys_in_any_order() {
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let nodes = get_sample_multiproof_nodes();
+        let mut keys = get_sample_multiproof_keys();
+        keys.reverse();
+        let result = verify_multiproof_nodes(&nodes, &root, keys).unwrap();
+        assert!(result.len() == MULTIPROOF_INDICES.len());
+    }
+
+    #[test]
+    fn should_fail_to_verify_multiproof_missing_a_node() {
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let mut nodes = get_sample_multiproof_nodes();
+        let missing_node = nodes.pop().unwrap();
+        let expected_error = format!(
+            "✘ Proof Error: Multiproof is missing node {:?}!",
+            keccak_hash_bytes(&missing_node).unwrap()
+        );
+        match verify_multiproof_nodes(&nodes, &root, get_sample_multiproof_keys()) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Verifying multiproof missing a node should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_multiproof_with_unneeded_nodes() {
+        let expected_error =
+            "✘ Proof Error: Multiproof contains duplicate nodes or nodes no key needs!";
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let nodes = get_sample_multiproof_nodes();
+        let keys = get_sample_multiproof_keys()[..1].to_vec();
+        match verify_multiproof_nodes(&nodes, &root, keys) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Verifying multiproof with unneeded nodes should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_multiproof_against_wrong_root() {
+        let wrong_root = convert_hex_to_h256(RECEIPTS_ROOT_3.to_string()).unwrap();
+        match verify_multiproof_nodes(
+            &get_sample_multiproof_nodes(),
+            &wrong_root,
+            get_sample_multiproof_keys(),
+        ) {
+            Err(AppError::Custom(e)) => assert!(e.contains("Multiproof is missing node")),
+            _ => panic!("Verifying against the wrong root should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_multiproof_for_index_not_covered() {
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let hex_proof = get_sample_multiproof();
+        assert!(verify_receipt_multiproof(&hex_proof, &root, &[0, 3, 14, 16]).is_err());
+    }
+
+    fn get_sample_exclusion_proof(index: usize) -> String {
+        get_hex_proof_from_branch(
+            &get_exclusion_branch_from_trie(
+                &get_sample_trie_with_sample_receipts(
+                    SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+                    get_sample_tx_hashes_1(),
+                ),
+                index,
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn get_sample_transaction_count_proof(count: usize) -> (String, String) {
+        let trie = get_transactions_trie_from_transactions(&get_sample_transactions()).unwrap();
+        let (inclusion_branch, exclusion_branch) =
+            get_transaction_count_branches_from_trie(&trie, count).unwrap();
+        (
+            get_hex_proof_from_branch(&inclusion_branch).unwrap(),
+            get_hex_proof_from_branch(&exclusion_branch).unwrap(),
+        )
+    }
+
+    #[test]
+    fn should_verify_receipt_exclusion_proofs() {
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let num_receipts = get_sample_tx_hashes_1().len();
+        vec![num_receipts, num_receipts + 1, 0x80 + num_receipts, 1337]
+            .into_iter()
+            .for_each(|index| {
+                verify_receipt_exclusion_proof(&get_sample_exclusion_proof(index), &root, index)
+                    .unwrap()
+            });
+    }
+
+    #[test]
+    fn should_verify_exclusion_proof_against_empty_trie() {
+        let hex_proof = get_hex_proof_from_branch(&[]).unwrap();
+        verify_receipt_exclusion_proof(&hex_proof, &HASHED_NULL_NODE, 0).unwrap();
+    }
+
+    #[test]
+    fn should_fail_to_verify_exclusion_proof_of_included_key() {
+        let expected_error = "✘ Proof Error: Key is in the trie so cannot be excluded!";
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        match verify_receipt_exclusion_proof(&get_sample_proof_1(), &root, PROOF_1_INDEX) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Excluding an included key should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_truncated_exclusion_proof() {
+        let expected_error =
+            "✘ Proof Error: Ran out of nodes before reaching the key's divergence point!";
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let index = get_sample_tx_hashes_1().len();
+        let mut rlp_encoded_nodes =
+            decode_hex_proof_to_rlp_encoded_nodes(&get_sample_exclusion_proof(index)).unwrap();
+        rlp_encoded_nodes.pop();
+        let key = convert_usize_index_to_trie_key(index).unwrap();
+        match verify_exclusion_proof_nodes(&rlp_encoded_nodes, &root, key) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Verifying a truncated exclusion proof should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_exclusion_proof_with_nodes_after_divergence() {
+        let expected_error =
+            "✘ Proof Error: Proof contains nodes after the key's divergence point!";
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let index = get_sample_tx_hashes_1().len();
+        let mut rlp_encoded_nodes =
+            decode_hex_proof_to_rlp_encoded_nodes(&get_sample_exclusion_proof(index)).unwrap();
+        rlp_encoded_nodes.push(rlp_encoded_nodes[0].clone());
+        let key = convert_usize_index_to_trie_key(index).unwrap();
+        match verify_exclusion_proof_nodes(&rlp_encoded_nodes, &root, key) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Verifying an over-long exclusion proof should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_empty_exclusion_proof_against_non_empty_trie() {
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let hex_proof = get_hex_proof_from_branch(&[]).unwrap();
+        assert!(verify_receipt_exclusion_proof(&hex_proof, &root, 1337).is_err());
+    }
+
+    #[test]
+    fn should_verify_transaction_count_proof() {
+        let count = get_sample_transactions().len();
+        let root = convert_hex_to_h256(TRANSACTIONS_ROOT.to_string()).unwrap();
+        let (inclusion_proof, exclusion_proof) = get_sample_transaction_count_proof(count);
+        let result =
+            verify_transaction_count_proof(&inclusion_proof, &exclusion_proof, &root, count)
+                .unwrap();
+        assert!(result == count);
+    }
+
+    #[test]
+    fn should_verify_transaction_count_proof_of_empty_block() {
+        let empty_proof = get_hex_proof_from_branch(&[]).unwrap();
+        let result =
+            verify_transaction_count_proof(&empty_proof, &empty_proof, &HASHED_NULL_NODE, 0)
+                .unwrap();
+        assert!(result == 0);
+    }
+
+    #[test]
+    fn should_fail_to_verify_transaction_count_proof_for_wrong_count() {
+        let count = get_sample_transactions().len();
+        let root = convert_hex_to_h256(TRANSACTIONS_ROOT.to_string()).unwrap();
+        let (inclusion_proof, exclusion_proof) = get_sample_transaction_count_proof(count);
+        assert!(verify_transaction_count_proof(
+            &inclusion_proof,
+            &exclusion_proof,
+            &root,
+            count - 1
+        )
+        .is_err());
+        assert!(verify_transaction_count_proof(
+            &inclusion_proof,
+            &exclusion_proof,
+            &root,
+            count + 1
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn should_fail_to_verify_zero_count_proof_with_non_empty_inclusion_proof() {
+        let expected_error = "✘ Proof Error: A count of zero needs an empty inclusion proof!";
+        let count = get_sample_transactions().len();
+        let root = convert_hex_to_h256(TRANSACTIONS_ROOT.to_string()).unwrap();
+        let (inclusion_proof, exclusion_proof) = get_sample_transaction_count_proof(count);
+        match verify_transaction_count_proof(&inclusion_proof, &exclusion_proof, &root, 0) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Verifying zero count should not have succeeded!"),
+        }
+    }
+
+    fn get_sample_range_proof(first_index: usize, last_index: usize) -> (String, Vec<Bytes>) {
+        let (nodes, rlp_encoded_receipts) = get_range_branch_from_trie(
+            &get_sample_trie_with_sample_receipts(
+                SAMPLE_RECEIPT_JSONS_1_PATH.to_string(),
+                get_sample_tx_hashes_1(),
+            ),
+            first_index,
+            last_index,
+        )
+        .unwrap();
+        (
+            get_hex_proof_from_branch(&nodes).unwrap(),
+            rlp_encoded_receipts,
+        )
+    }
+
+    #[test]
+    fn should_verify_receipt_range_proof() {
+        let (first_index, last_index) = (3, 10);
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let (hex_proof, rlp_encoded_receipts) = get_sample_range_proof(first_index, last_index);
+        rlp_encoded_receipts
+            .iter()
+            .enumerate()
+            .for_each(|(i, rlp_encoded_receipt)| {
+                assert!(
+                    rlp_encoded_receipt
+                        == &get_expected_rlp_encoded_receipt(
+                            SAMPLE_RECEIPT_JSONS_1_PATH,
+                            get_sample_tx_hashes_1(),
+                            first_index + i,
+                        )
+                )
+            });
+        verify_receipt_range_proof(
+            &hex_proof,
+            &root,
+            first_index,
+            last_index,
+            &rlp_encoded_receipts,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn should_verify_range_proofs_over_various_ranges() {
+        let trie = get_sample_trie_with_indexed_values(300);
+        vec![
+            (0, 0),
+            (0, 5),
+            (5, 5),
+            (1, 127),
+            (120, 140),
+            (127, 128),
+            (255, 256),
+            (298, 299),
+            (0, 299),
+        ]
+        .into_iter()
+        .for_each(|(first_index, last_index)| {
+            let (nodes, values) =
+                get_range_branch_from_trie(&trie, first_index, last_index).unwrap();
+            let hex_proof = get_hex_proof_from_branch(&nodes).unwrap();
+            verify_receipt_range_proof(&hex_proof, &trie.root, first_index, last_index, &values)
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn should_fail_to_verify_range_proof_omitting_a_receipt() {
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let (hex_proof, mut rlp_encoded_receipts) = get_sample_range_proof(3, 10);
+        rlp_encoded_receipts.remove(3);
+        assert!(
+            verify_receipt_range_proof(&hex_proof, &root, 3, 9, &rlp_encoded_receipts).is_err()
+        );
+    }
+
+    #[test]
+    fn should_fail_to_verify_range_proof_with_tampered_receipt() {
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let (hex_proof, mut rlp_encoded_receipts) = get_sample_range_proof(3, 10);
+        rlp_encoded_receipts[4].push(0x00);
+        assert!(
+            verify_receipt_range_proof(&hex_proof, &root, 3, 10, &rlp_encoded_receipts).is_err()
+        );
+    }
+
+    #[test]
+    fn should_fail_to_verify_range_proof_if_range_does_not_match_receipts() {
+        let expected_error = "✘ Proof Error: Range [3, 11] does not match the 8 receipts given!";
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let (hex_proof, rlp_encoded_receipts) = get_sample_range_proof(3, 10);
+        match verify_receipt_range_proof(&hex_proof, &root, 3, 11, &rlp_encoded_receipts) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Verifying range proof should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_range_proof_with_unneeded_nodes() {
+        let expected_error =
+            "✘ Proof Error: Range proof contains duplicate nodes or nodes the range does not need!";
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let (hex_proof, rlp_encoded_receipts) = get_sample_range_proof(3, 10);
+        let mut rlp_encoded_nodes = decode_hex_proof_to_rlp_encoded_nodes(&hex_proof).unwrap();
+        rlp_encoded_nodes.push(rlp_encoded_nodes[0].clone());
+        let key_value_tuples = (3..=10)
+            .zip(rlp_encoded_receipts)
+            .map(|(index, receipt)| (convert_usize_index_to_trie_key(index).unwrap(), receipt))
+            .collect();
+        match verify_range_proof_nodes(&rlp_encoded_nodes, &root, key_value_tuples) {
+            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            _ => panic!("Verifying range proof should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_range_proof_missing_a_node() {
+        let root = convert_hex_to_h256(RECEIPTS_ROOT_1.to_string()).unwrap();
+        let (hex_proof, rlp_encoded_receipts) = get_sample_range_proof(3, 10);
+        let mut rlp_encoded_nodes = decode_hex_proof_to_rlp_encoded_nodes(&hex_proof).unwrap();
+        rlp_encoded_nodes.pop();
+        let key_value_tuples = (3..=10)
+            .zip(rlp_encoded_receipts)
+            .map(|(index, receipt)| (convert_usize_index_to_trie_key(index).unwrap(), receipt))
+            .collect();
+        match verify_range_proof_nodes(&rlp_encoded_nodes, &root, key_value_tuples) {
+            Err(AppError::Custom(e)) => assert!(e.contains("Rebuilt subtree")),
+            _ => panic!("Verifying range proof should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_verify_proofs_in_trie_with_inline_nodes() {
+        let trie = get_sample_trie_with_inline_nodes();
+        let root = convert_hex_to_h256(INLINE_TRIE_ROOT.to_string()).unwrap();
+        get_sample_inline_key_value_tuples()
+            .into_iter()
+            .for_each(|(key, value)| {
+                let (_, node_stack, _) = trie.find(key.clone()).unwrap();
+                let rlp_encoded_nodes = get_rlp_encoded_nodes_from_node_stack(&node_stack);
+                assert!(rlp_encoded_nodes.len() <= node_stack.len());
+                let result = verify_proof_nodes(&rlp_encoded_nodes, &root, key).unwrap();
+                assert!(result == value);
+            });
+    }
+
+    #[test]
+    fn should_verify_exclusion_proofs_in_trie_with_inline_nodes() {
+        let trie = get_sample_trie_with_inline_nodes();
+        let root = convert_hex_to_h256(INLINE_TRIE_ROOT.to_string()).unwrap();
+        vec!["d", "dot", "dogs", "doges", "hors", "horses"]
+            .into_iter()
+            .for_each(|key_str| {
+                let key = get_nibbles_from_bytes(key_str.as_bytes().to_vec());
+                let (_, node_stack, _) = trie.find(key.clone()).unwrap();
+                let rlp_encoded_nodes = get_rlp_encoded_nodes_from_node_stack(&node_stack);
+                assert!(verify_exclusion_proof_nodes(&rlp_encoded_nodes, &root, key).is_ok());
+            });
+    }
+
+    #[test]
+    fn should_verify_multiproof_in_trie_with_inline_nodes() {
+        let trie = get_sample_trie_with_inline_nodes();
+        let root = convert_hex_to_h256(INLINE_TRIE_ROOT.to_string()).unwrap();
+        let key_value_tuples = get_sample_inline_key_value_tuples();
+        let node_stack =
+            key_value_tuples
+                .iter()
+                .fold(Vec::new(), |mut node_stack: NodeStack, (key, _)| {
+                    let (_, branch, _) = trie.find(key.clone()).unwrap();
+                    branch.into_iter().for_each(|node| {
+                        if !node_stack.contains(&node) {
+                            node_stack.push(node)
+                        }
+                    });
+                    node_stack
+                });
+        let rlp_encoded_nodes = get_rlp_encoded_nodes_from_node_stack(&node_stack);
+        let (keys, expected_result): (Vec<Nibbles>, Vec<Bytes>) =
+            key_value_tuples.into_iter().unzip();
+        let result = verify_multiproof_nodes(&rlp_encoded_nodes, &root, keys).unwrap();
+        assert!(result == expected_result);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::parse_cli_args::CliArgs;
+use rusty_receipt_proof_maker::{
+    convert_bytes_to_hex, convert_hex_to_h256, convert_num_string_to_usize, verify_receipt_proof,
+    Result,
+};
+
+pub fn verify_proof_from_cli_args(cli_args: CliArgs) -> Result<String> {
+    verify_receipt_proof(
+        &cli_args.arg_proof,
+        &convert_hex_to_h256(cli_args.arg_receiptsroot)?,
+        convert_num_string_to_usize(&cli_args.arg_index)?,
+    )
+    .map(|rlp_encoded_receipt| {
+        info!("✔ Proof verified! RLP-encoded receipt:\n");
+        convert_bytes_to_hex(rlp_encoded_receipt)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{
+        PROOF_1_INDEX, RECEIPTS_ROOT_1, RECEIPTS_ROOT_3, SAMPLE_PROOF_1_PATH,
+    };
+    use rusty_receipt_proof_maker::AppError;
+    use std::fs;
+
+    fn get_verify_cli_args(receipts_root: &str, index: usize) -> CliArgs {
+        CliArgs {
+            cmd_tx: false,
+            cmd_bundle: false,
+            cmd_verify: true,
+            cmd_account: false,
+            flag_header: false,
+            flag_verbose: false,
+            arg_txhash: String::new(),
+            arg_proof: fs::read_to_string(SAMPLE_PROOF_1_PATH).unwrap(),
+            arg_receiptsroot: receipts_root.to_string(),
+            arg_address: String::new(),
+            arg_storagekey: Vec::new(),
+            flag_block: String::new(),
+            arg_index: index.to_string(),
+        }
+    }
+
+    #[test]
+    fn should_verify_proof_from_cli_args() {
+        let cli_args = get_verify_cli_args(RECEIPTS_ROOT_1, PROOF_1_INDEX);
+        let result = verify_proof_from_cli_args(cli_args).unwrap();
+        assert!(!result.is_empty());
+    }
+
+    #[test]
+    fn should_fail_to_verify_proof_from_cli_args_with_wrong_root() {
+        let cli_args = get_verify_cli_args(RECEIPTS_ROOT_3, PROOF_1_INDEX);
+        match verify_proof_from_cli_args(cli_args) {
+            Err(AppError::Custom(e)) => assert!(e.contains("✘ Proof Error")),
+            _ => panic!("Verifying against the wrong root should not have succeeded!"),
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use crate::{
+    constants::{HASHED_EMPTY_CODE, HASHED_NULL_NODE},
+    errors::AppError,
+    nibble_utils::Nibbles,
+    rlp_codec::{rlp_decode_account, rlp_decode_storage_value},
+    trie::get_secure_trie_key,
+    types::{Account, AccountProof, Result, StorageProof},
+    verify_proof::{verify_exclusion_proof_nodes, verify_proof_nodes},
+};
+use ethereum_types::{H256, U256};
+
+fn is_empty_account(account: &Account) -> bool {
+    account.nonce.is_zero()
+        && account.balance.is_zero()
+        && account.storage_root == HASHED_NULL_NODE
+        && account.code_hash == HASHED_EMPTY_CODE
+}
+fn verify_account_inclusion_proof(
+    account_proof: &AccountProof,
+    state_root: &H256,
+    key: Nibbles,
+) -> Result<Account> {
+    verify_proof_nodes(&account_proof.account_proof, state_root, key)
+        .and_then(|rlp_encoded_account| rlp_decode_account(&rlp_encoded_account))
+        .and_then(|account| match account == account_proof.account {
+            true => Ok(account),
+            false => Err(AppError::Custom(format!(
+                "✘ Proof Error: Proven account does not match the one in the response!\n    ✘ Proven account:   {:?}\n    ✘ Response account: {:?}",
+                account, account_proof.account
+            ))),
+        })
+}
+/**
+ *
+ * Verifying Account & Storage Proofs:
+ *
+ * Both tries are secure, so each proof is followed under the keccak hash of
+ * the address or storage slot. An unset storage slot is never held in its
+ * trie, so `eth_getProof` returns a zero value with a proof of exclusion,
+ * which is verified as such instead.
+ *
+ * An empty account is usually absent too, never having been created or
+ * having been removed per EIP-161, & so comes with a proof of exclusion.
+ * Empty accounts touched before EIP-161 may still be in the state trie
+ * though, so for an empty account a proof of its inclusion is accepted too.
+ *
+ */
+pub fn verify_account_proof(account_proof: &AccountProof, state_root: &H256) -> Result<Account> {
+    info!(
+        "✔ Verifying account proof for {:?} against state root: {}",
+        account_proof.address, state_root
+    );
+    get_secure_trie_key(account_proof.address.as_bytes()).and_then(|key| {
+        match is_empty_account(&account_proof.account) {
+            true => {
+                verify_exclusion_proof_nodes(&account_proof.account_proof, state_root, key.clone())
+                    .map(|_| account_proof.account.clone())
+                    .or_else(|_| verify_account_inclusion_proof(account_proof, state_root, key))
+            }
+            false => verify_account_inclusion_proof(account_proof, state_root, key),
+        }
+    })
+}
+
+pub fn verify_storage_proof(storage_proof: &StorageProof, storage_root: &H256) -> Result<U256> {
+    get_secure_trie_key(storage_proof.key.as_bytes()).and_then(|key| {
+        match storage_proof.value.is_zero() {
+            true => verify_exclusion_proof_nodes(&storage_proof.proof, storage_root, key)
+                .map(|_| storage_proof.value),
+            false => verify_proof_nodes(&storage_proof.proof, storage_root, key)
+                .and_then(|rlp_encoded_value| rlp_decode_storage_value(&rlp_encoded_value))
+                .and_then(|value| match value == storage_proof.value {
+                    true => Ok(value),
+                    false => Err(AppError::Custom(format!(
+                        "✘ Proof Error: Proven value of storage slot {:?} does not match the response!\n    ✘ Proven value:   {}\n    ✘ Response value: {}",
+                        storage_proof.key, value, storage_proof.value
+                    ))),
+                }),
+        }
+    })
+}
+
+pub fn verify_account_and_storage_proofs(
+    account_proof: &AccountProof,
+    state_root: &H256,
+) -> Result<Account> {
+    verify_account_proof(account_proof, state_root).and_then(|account| {
+        info!(
+            "✔ Verifying {} storage proof(s) against storage root: {}",
+            account_proof.storage_proof.len(),
+            account.storage_root
+        );
+        account_proof
+            .storage_proof
+            .iter()
+            .map(|storage_proof| verify_storage_proof(storage_proof, &account.storage_root))
+            .collect::<Result<Vec<U256>>>()
+            .map(|_| account)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nibble_utils::get_nibbles_from_bytes;
+    use crate::test_utils::{
+        get_rlp_encoded_nodes_from_node_stack, get_sample_account_proof, get_sample_state_root,
+    };
+    use crate::trie::Trie;
+    use crate::types::Bytes;
+    use ethereum_types::Address;
+    use rlp::RlpStream;
+
+    const ABSENT_ADDRESS: u64 = 1;
+
+    fn get_empty_account() -> Account {
+        Account {
+            nonce: U256::zero(),
+            balance: U256::zero(),
+            storage_root: HASHED_NULL_NODE,
+            code_hash: HASHED_EMPTY_CODE,
+        }
+    }
+
+    fn get_all_storage_proof_nodes(account_proof: &AccountProof) -> Vec<Bytes> {
+        account_proof
+            .storage_proof
+            .iter()
+            .flat_map(|storage_proof| storage_proof.proof.clone())
+            .collect()
+    }
+
+    fn get_exclusion_proof_from_proof_nodes(
+        root: H256,
+        proof_nodes: Vec<Bytes>,
+        unhashed_key: &[u8],
+    ) -> Vec<Bytes> {
+        let trie = Trie::get_partial_trie_from_proof_nodes(root, &proof_nodes).unwrap();
+        let (_, node_stack, _) = trie
+            .find(get_secure_trie_key(unhashed_key).unwrap())
+            .unwrap();
+        get_rlp_encoded_nodes_from_node_stack(&node_stack)
+    }
+
+    #[test]
+    fn should_verify_account_proof() {
+        let account_proof = get_sample_account_proof();
+        let result = verify_account_proof(&account_proof, &get_sample_state_root()).unwrap();
+        assert!(result == account_proof.account);
+        assert!(result.nonce == U256::one());
+    }
+
+    #[test]
+    fn should_verify_each_storage_proof() {
+        let account_proof = get_sample_account_proof();
+        let storage_root = account_proof.account.storage_root;
+        account_proof
+            .storage_proof
+            .iter()
+            .for_each(|storage_proof| {
+                let result = verify_storage_proof(storage_proof, &storage_root).unwrap();
+                assert!(result == storage_proof.value);
+            });
+    }
+
+    #[test]
+    fn should_verify_account_and_storage_proofs() {
+        let account_proof = get_sample_account_proof();
+        let result =
+            verify_account_and_storage_proofs(&account_proof, &get_sample_state_root()).unwrap();
+        assert!(result == account_proof.account);
+    }
+
+    #[test]
+    fn should_fail_to_verify_account_proof_against_wrong_state_root() {
+        let account_proof = get_sample_account_proof();
+        let wrong_root = account_proof.account.storage_root;
+        match verify_account_proof(&account_proof, &wrong_root) {
+            Err(AppError::Custom(e)) => assert!(e.contains("does not match its parent's")),
+            _ => panic!("Verifying against the wrong root should not have succeeded!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_account_proof_if_response_account_differs() {
+        let mut account_proof = get_sample_account_proof();
+        let proven_account = account_proof.account.clone();
+        account_proof.account.balance = U256::from(1337);
+        let expected_err = format!(
+            "✘ Proof Error: Proven account does not match the one in the response!\n    ✘ Proven account:   {:?}\n    ✘ Response account: {:?}",
+            proven_account, account_proof.account
+        );
+        match verify_account_proof(&account_proof, &get_sample_state_root()) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Account mismatch should not have verified!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_storage_proof_if_response_value_differs() {
+        let account_proof = get_sample_account_proof();
+        let mut storage_proof = account_proof.storage_proof[0].clone();
+        storage_proof.value = U256::from(1338);
+        let expected_err = format!(
+            "✘ Proof Error: Proven value of storage slot {:?} does not match the response!\n    ✘ Proven value:   {}\n    ✘ Response value: {}",
+            storage_proof.key,
+            U256::from(0x539),
+            storage_proof.value
+        );
+        match verify_storage_proof(&storage_proof, &account_proof.account.storage_root) {
+            Err(AppError::Custom(e)) => assert!(e == expected_err),
+            _ => panic!("Storage value mismatch should not have verified!"),
+        }
+    }
+
+    #[test]
+    fn should_verify_storage_proof_of_unset_slot() {
+        let account_proof = get_sample_account_proof();
+        let storage_root = account_proof.account.storage_root;
+        let key = H256::from_low_u64_be(3);
+        let storage_proof = StorageProof {
+            key,
+            value: U256::zero(),
+            proof: get_exclusion_proof_from_proof_nodes(
+                storage_root,
+                get_all_storage_proof_nodes(&account_proof),
+                key.as_bytes(),
+            ),
+        };
+        let result = verify_storage_proof(&storage_proof, &storage_root).unwrap();
+        assert!(result.is_zero());
+    }
+
+    #[test]
+    fn should_fail_to_verify_storage_proof_of_zero_value_in_set_slot() {
+        let account_proof = get_sample_account_proof();
+        let mut storage_proof = account_proof.storage_proof[0].clone();
+        storage_proof.value = U256::zero();
+        match verify_storage_proof(&storage_proof, &account_proof.account.storage_root) {
+            Err(AppError::Custom(e)) => assert!(e.contains("Key is in the trie")),
+            _ => panic!("Zero value of a set storage slot should not have verified!"),
+        }
+    }
+
+    #[test]
+    fn should_verify_account_and_storage_proofs_of_absent_account() {
+        let sample_account_proof = get_sample_account_proof();
+        let address = Address::from_low_u64_be(ABSENT_ADDRESS);
+        let account_proof = AccountProof {
+            address,
+            account: get_empty_account(),
+            account_proof: get_exclusion_proof_from_proof_nodes(
+                get_sample_state_root(),
+                sample_account_proof.account_proof,
+                address.as_bytes(),
+            ),
+            storage_proof: vec![StorageProof {
+                key: H256::zero(),
+                value: U256::zero(),
+                proof: vec![],
+            }],
+        };
+        let result =
+            verify_account_and_storage_proofs(&account_proof, &get_sample_state_root()).unwrap();
+        assert!(result == get_empty_account());
+    }
+
+    #[test]
+    fn should_verify_inclusion_proof_of_empty_account_still_in_state() {
+        let address = Address::from_low_u64_be(ABSENT_ADDRESS);
+        let account = get_empty_account();
+        let mut rlp_stream = RlpStream::new();
+        rlp_stream
+            .begin_list(4)
+            .append(&account.nonce)
+            .append(&account.balance)
+            .append(&account.storage_root)
+            .append(&account.code_hash);
+        let mut trie = Trie::get_new_secure_trie().unwrap();
+        trie.put(
+            get_nibbles_from_bytes(address.as_bytes().to_vec()),
+            rlp_stream.out(),
+        )
+        .unwrap();
+        let (_, node_stack, _) = trie
+            .find(get_secure_trie_key(address.as_bytes()).unwrap())
+            .unwrap();
+        let account_proof = AccountProof {
+            address,
+            account: account.clone(),
+            account_proof: get_rlp_encoded_nodes_from_node_stack(&node_stack),
+            storage_proof: vec![],
+        };
+        let result = verify_account_proof(&account_proof, &trie.root).unwrap();
+        assert!(result == account);
+    }
+
+    #[test]
+    fn should_fail_to_verify_empty_account_for_address_in_state() {
+        let mut account_proof = get_sample_account_proof();
+        account_proof.account = get_empty_account();
+        match verify_account_proof(&account_proof, &get_sample_state_root()) {
+            Err(AppError::Custom(e)) => assert!(e.contains("Proven account does not match")),
+            _ => panic!("Empty account for an address in state should not have verified!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_storage_proof_for_wrong_key() {
+        let account_proof = get_sample_account_proof();
+        let mut storage_proof = account_proof.storage_proof[0].clone();
+        storage_proof.key = account_proof.storage_proof[1].key;
+        assert!(verify_storage_proof(&storage_proof, &account_proof.account.storage_root).is_err());
+    }
+}